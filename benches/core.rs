@@ -0,0 +1,93 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use num_bigint::BigInt;
+use num_traits::Num;
+use rbtc::ecc::field_element::FieldElement;
+use rbtc::ecc::point::s256_point::S256Point;
+use rbtc::ecc::private_key::PrivateKey;
+use rbtc::ecc::s256_field::S256Field;
+use rbtc::ecc::scalar::Scalar;
+use rbtc::ecc::signature::hash_to_scalar;
+
+fn from_hex(input: &str) -> BigInt {
+    BigInt::from_str_radix(input, 16).unwrap()
+}
+
+fn field_element(c: &mut Criterion) {
+    let a = FieldElement::new(17, 223).unwrap();
+    let b = FieldElement::new(95, 223).unwrap();
+
+    c.bench_function("field_element/mul", |bench| {
+        bench.iter(|| black_box(&a) * black_box(&b))
+    });
+    c.bench_function("field_element/div", |bench| {
+        bench.iter(|| black_box(&a) / black_box(&b))
+    });
+}
+
+fn s256_field(c: &mut Criterion) {
+    let a = S256Field::new(from_hex(
+        "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+    ))
+    .unwrap();
+    let b = S256Field::new(from_hex(
+        "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+    ))
+    .unwrap();
+
+    c.bench_function("s256_field/add", |bench| {
+        bench.iter(|| black_box(&a) + black_box(&b))
+    });
+    c.bench_function("s256_field/mul", |bench| {
+        bench.iter(|| black_box(&a) * black_box(&b))
+    });
+    c.bench_function("s256_field/div", |bench| {
+        bench.iter(|| black_box(&a) / black_box(&b))
+    });
+    c.bench_function("s256_field/sqrt", |bench| {
+        bench.iter(|| black_box(&b).sqrt())
+    });
+}
+
+fn scalar_multiplication(c: &mut Criterion) {
+    let g = S256Point::generator();
+    let k = Scalar::new(from_hex(
+        "8f68b9d2f63b5f339239c1ad981f162ee88c5678723ea3351b7b444c9ec4c0da",
+    ));
+
+    let mut group = c.benchmark_group("scalar_mul_g");
+    group.sample_size(20);
+    group.bench_function("double_and_add", |bench| {
+        bench.iter(|| black_box(k.clone()) * black_box(&g))
+    });
+    group.bench_function("montgomery_ladder", |bench| {
+        bench.iter(|| black_box(&g).mul_ct(black_box(&k)))
+    });
+    group.bench_function("glv", |bench| {
+        bench.iter(|| black_box(&g).mul_glv(black_box(&k)))
+    });
+    group.finish();
+}
+
+fn ecdsa(c: &mut Criterion) {
+    let key = PrivateKey::new(Scalar::from(12345u32)).unwrap();
+    let public_key = key.public_key().unwrap();
+    let z = hash_to_scalar(b"Programming Bitcoin!");
+    let sig = key.sign(&z).unwrap();
+
+    let mut group = c.benchmark_group("ecdsa");
+    group.sample_size(20);
+    group.bench_function("sign", |bench| bench.iter(|| key.sign(black_box(&z))));
+    group.bench_function("verify", |bench| {
+        bench.iter(|| public_key.verify(black_box(&z), black_box(&sig)))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    field_element,
+    s256_field,
+    scalar_multiplication,
+    ecdsa
+);
+criterion_main!(benches);
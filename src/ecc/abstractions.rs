@@ -1,5 +1,6 @@
-use std::fmt::Display;
-use std::ops::{Add, Div, Mul, Sub};
+use alloc::string::ToString;
+use core::fmt::Display;
+use core::ops::{Add, Div, Mul, Sub};
 
 use num_bigint::BigInt;
 use num_traits::{One, Zero};
@@ -25,14 +26,46 @@ pub trait FieldElementTrait:
     fn get_prime(&self) -> &BigInt;
     fn from_values(num: BigInt, prime: BigInt) -> Result<Self, FieldElementError>;
 
+    /// Additive identity of the field `self` belongs to.
+    fn zero(&self) -> ArithmeticResult<Self> {
+        Self::from_values(BigInt::zero(), self.get_prime().clone())
+    }
+
+    /// Additive inverse `(p - num) % p`, so that zero maps to zero rather than `p`.
+    fn neg(&self) -> ArithmeticResult<Self> {
+        let prime = self.get_prime();
+        Self::from_values((prime - self.get_num()) % prime, prime.clone())
+    }
+
+    /// Owned-exponent convenience over [`FieldElementTrait::pow_mod_ref`].
+    ///
+    /// Panics where `pow_mod_ref` would return an error; prefer `pow_mod_ref` in fallible
+    /// code and wherever the exponent is borrowed.
     fn pow_mod(&self, exponent: BigInt) -> Self {
-        let mut n = exponent;
-        let prime: &BigInt = self.get_prime();
-        while n < BigInt::zero() {
-            n += prime - BigInt::one();
-        }
-        let num = self.get_num().modpow(&n, prime);
-        Self::from_values(num, prime.clone()).expect("invalid params")
+        self.pow_mod_ref(&exponent).expect("invalid params")
+    }
+
+    /// `self * self`, reduced once; cheaper and clearer than `pow_mod(2)`.
+    fn square(&self) -> ArithmeticResult<Self> {
+        let num = self.get_num() * self.get_num() % self.get_prime();
+        Self::from_values(num, self.get_prime().clone())
+    }
+
+    /// `self^exponent` without taking ownership of the exponent. Negative exponents are
+    /// reduced modulo `p - 1` by Fermat's little theorem.
+    fn pow_mod_ref(&self, exponent: &BigInt) -> ArithmeticResult<Self> {
+        let prime = self.get_prime();
+        let num = if *exponent < BigInt::zero() {
+            let order = prime - BigInt::one();
+            let mut n = exponent % &order;
+            if n < BigInt::zero() {
+                n += &order;
+            }
+            self.get_num().modpow(&n, prime)
+        } else {
+            self.get_num().modpow(exponent, prime)
+        };
+        Self::from_values(num, prime.clone())
     }
 
     fn check_primes(&self, other: &Self) -> Result<(), FieldElementError> {
@@ -0,0 +1,506 @@
+//! BIP32 hierarchical deterministic keys. An extended key is a key plus a 32-byte chain
+//! code; children are derived from `HMAC-SHA512(chain code, data || index)`, whose left half
+//! tweaks the parent key and whose right half becomes the child's chain code.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+
+use crate::ecc::error::{Bip32Error, FieldElementError};
+use crate::ecc::point::s256_point::S256Point;
+use crate::ecc::private_key::PrivateKey;
+use crate::ecc::scalar::Scalar;
+use crate::encoding::base58::{base58check_decode, base58check_encode};
+use crate::encoding::error::EncodingError;
+use crate::encoding::hex;
+
+/// Indices at or above this are hardened and can only be derived from a private key.
+pub const HARDENED: u32 = 0x8000_0000;
+
+// serialization version bytes
+const XPRV: [u8; 4] = [0x04, 0x88, 0xad, 0xe4];
+const XPUB: [u8; 4] = [0x04, 0x88, 0xb2, 0x1e];
+const TPRV: [u8; 4] = [0x04, 0x35, 0x83, 0x94];
+const TPUB: [u8; 4] = [0x04, 0x35, 0x87, 0xcf];
+
+#[derive(Clone)]
+pub struct ExtendedPrivKey {
+    pub private_key: PrivateKey,
+    pub chain_code: [u8; 32],
+    pub depth: u8,
+    pub parent_fingerprint: [u8; 4],
+    pub child_number: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtendedPubKey {
+    pub public_key: S256Point,
+    pub chain_code: [u8; 32],
+    pub depth: u8,
+    pub parent_fingerprint: [u8; 4],
+    pub child_number: u32,
+}
+
+impl ExtendedPrivKey {
+    /// Master key `m` from a seed: `HMAC-SHA512("Bitcoin seed", seed)`.
+    pub fn new_master(seed: &[u8]) -> Result<ExtendedPrivKey, FieldElementError> {
+        let (key, chain_code) = hmac_sha512(b"Bitcoin seed", &[seed]);
+        Ok(ExtendedPrivKey {
+            private_key: PrivateKey::from_bytes(&key)?,
+            chain_code,
+            depth: 0,
+            parent_fingerprint: [0; 4],
+            child_number: 0,
+        })
+    }
+
+    /// CKDpriv: hardened children hash `0x00 || k_par`, normal ones the compressed SEC
+    /// of the parent public key. The child secret is `parse256(I_L) + k_par (mod N)`.
+    pub fn derive_child(&self, index: u32) -> Result<ExtendedPrivKey, Bip32Error> {
+        let public_key = self.private_key.public_key()?;
+        let secret = self.private_key.secret();
+        let (il, chain_code) = if index >= HARDENED {
+            hmac_sha512(
+                &self.chain_code,
+                &[&[0x00], &secret.to_bytes(), &index.to_be_bytes()],
+            )
+        } else {
+            hmac_sha512(
+                &self.chain_code,
+                &[&public_key.to_sec_compressed(), &index.to_be_bytes()],
+            )
+        };
+
+        let tweak = Scalar::from_bytes(&il)?;
+        Ok(ExtendedPrivKey {
+            private_key: PrivateKey::new(tweak.add_mod_n(&secret))?,
+            chain_code,
+            depth: child_depth(self.depth)?,
+            parent_fingerprint: public_key.fingerprint(),
+            child_number: index,
+        })
+    }
+
+    /// BIP32 serialization, `xprv...` on mainnet and `tprv...` on testnet.
+    pub fn to_base58(&self, testnet: bool) -> String {
+        let mut key = [0u8; 33];
+        key[1..].copy_from_slice(&self.private_key.secret().to_bytes());
+        encode_extended(
+            if testnet { TPRV } else { XPRV },
+            self.depth,
+            self.parent_fingerprint,
+            self.child_number,
+            &self.chain_code,
+            &key,
+        )
+    }
+
+    /// Parses an `xprv`/`tprv` string into the key and whether it is a testnet key.
+    pub fn from_base58(s: &str) -> Result<(ExtendedPrivKey, bool), EncodingError> {
+        let (version, header, key) = decode_extended(s)?;
+        let testnet = match version {
+            XPRV => false,
+            TPRV => true,
+            _ => return Err(wrong_version(version, "private")),
+        };
+        if key[0] != 0x00 {
+            return Err(EncodingError::InvalidPayload(format!(
+                "private key data starts with 0x{:02x}, expected 0x00",
+                key[0]
+            )));
+        }
+        let private_key = PrivateKey::from_bytes(key[1..].try_into().unwrap())
+            .map_err(|err| EncodingError::InvalidPayload(err.to_string()))?;
+
+        let xprv = ExtendedPrivKey {
+            private_key,
+            chain_code: header.chain_code,
+            depth: header.depth,
+            parent_fingerprint: header.parent_fingerprint,
+            child_number: header.child_number,
+        };
+        Ok((xprv, testnet))
+    }
+
+    /// Neutered counterpart carrying the public key and the same chain code.
+    pub fn to_extended_pub(&self) -> Result<ExtendedPubKey, FieldElementError> {
+        Ok(ExtendedPubKey {
+            public_key: self.private_key.public_key()?.clone(),
+            chain_code: self.chain_code,
+            depth: self.depth,
+            parent_fingerprint: self.parent_fingerprint,
+            child_number: self.child_number,
+        })
+    }
+}
+
+/// The key itself prints through [`PrivateKey`]'s `Debug`, which redacts the secret.
+impl core::fmt::Debug for ExtendedPrivKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ExtendedPrivKey")
+            .field("private_key", &self.private_key)
+            .field(
+                "chain_code",
+                &format_args!("{}", hex::encode(&self.chain_code)),
+            )
+            .field("depth", &self.depth)
+            .field("parent_fingerprint", &self.parent_fingerprint)
+            .field("child_number", &self.child_number)
+            .finish()
+    }
+}
+
+impl ExtendedPubKey {
+    /// CKDpub: `K_i = parse256(I_L)*G + K_par`. Only normal (non-hardened) children can
+    /// be derived without the private key.
+    pub fn derive_child(&self, index: u32) -> Result<ExtendedPubKey, Bip32Error> {
+        if index >= HARDENED {
+            return Err(Bip32Error::HardenedFromPublic(
+                "hardened children need the private key".to_string(),
+            ));
+        }
+
+        let (il, chain_code) = hmac_sha512(
+            &self.chain_code,
+            &[&self.public_key.to_sec_compressed(), &index.to_be_bytes()],
+        );
+        let tweak = Scalar::from_bytes(&il)?;
+        let public_key = (&(&S256Point::generator() * &tweak)? + &self.public_key)?;
+        if public_key.x().is_none() {
+            return Err(FieldElementError::PointAtInfinity(
+                "child public key is infinity".to_string(),
+            )
+            .into());
+        }
+
+        Ok(ExtendedPubKey {
+            public_key,
+            chain_code,
+            depth: child_depth(self.depth)?,
+            parent_fingerprint: self.public_key.fingerprint(),
+            child_number: index,
+        })
+    }
+}
+
+impl ExtendedPubKey {
+    /// BIP32 serialization, `xpub...` on mainnet and `tpub...` on testnet.
+    ///
+    /// Panics if the public key is the point at infinity.
+    pub fn to_base58(&self, testnet: bool) -> String {
+        encode_extended(
+            if testnet { TPUB } else { XPUB },
+            self.depth,
+            self.parent_fingerprint,
+            self.child_number,
+            &self.chain_code,
+            &self.public_key.to_sec_compressed(),
+        )
+    }
+
+    /// Parses an `xpub`/`tpub` string into the key and whether it is a testnet key.
+    pub fn from_base58(s: &str) -> Result<(ExtendedPubKey, bool), EncodingError> {
+        let (version, header, key) = decode_extended(s)?;
+        let testnet = match version {
+            XPUB => false,
+            TPUB => true,
+            _ => return Err(wrong_version(version, "public")),
+        };
+        let public_key =
+            S256Point::parse(&key).map_err(|err| EncodingError::InvalidPayload(err.to_string()))?;
+
+        let xpub = ExtendedPubKey {
+            public_key,
+            chain_code: header.chain_code,
+            depth: header.depth,
+            parent_fingerprint: header.parent_fingerprint,
+            child_number: header.child_number,
+        };
+        Ok((xpub, testnet))
+    }
+}
+
+/// Fields shared by serialized private and public extended keys.
+struct Header {
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: u32,
+    chain_code: [u8; 32],
+}
+
+/// Base58check of `version || depth || parent fingerprint || child number || chain code ||
+/// key`, 78 bytes before the checksum.
+fn encode_extended(
+    version: [u8; 4],
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: u32,
+    chain_code: &[u8; 32],
+    key: &[u8; 33],
+) -> String {
+    let mut payload = Vec::with_capacity(78);
+    payload.extend_from_slice(&version);
+    payload.push(depth);
+    payload.extend_from_slice(&parent_fingerprint);
+    payload.extend_from_slice(&child_number.to_be_bytes());
+    payload.extend_from_slice(chain_code);
+    payload.extend_from_slice(key);
+    base58check_encode(&payload)
+}
+
+/// Splits a serialized extended key into its version, header and 33 key bytes. A master
+/// key (depth 0) must have a zero parent fingerprint and child number.
+fn decode_extended(s: &str) -> Result<([u8; 4], Header, [u8; 33]), EncodingError> {
+    let payload = base58check_decode(s)?;
+    if payload.len() != 78 {
+        return Err(EncodingError::InvalidLength(format!(
+            "extended key payload is {} bytes, expected 78",
+            payload.len()
+        )));
+    }
+
+    let header = Header {
+        depth: payload[4],
+        parent_fingerprint: payload[5..9].try_into().unwrap(),
+        child_number: u32::from_be_bytes(payload[9..13].try_into().unwrap()),
+        chain_code: payload[13..45].try_into().unwrap(),
+    };
+    if header.depth == 0 && (header.parent_fingerprint != [0; 4] || header.child_number != 0) {
+        return Err(EncodingError::InvalidPayload(
+            "master key with a parent fingerprint or child number".to_string(),
+        ));
+    }
+    Ok((
+        payload[..4].try_into().unwrap(),
+        header,
+        payload[45..].try_into().unwrap(),
+    ))
+}
+
+fn wrong_version(version: [u8; 4], kind: &str) -> EncodingError {
+    EncodingError::InvalidVersion(format!(
+        "version {:02x?} is not an extended {} key",
+        version, kind
+    ))
+}
+
+fn child_depth(depth: u8) -> Result<u8, Bip32Error> {
+    depth
+        .checked_add(1)
+        .ok_or_else(|| Bip32Error::MaxDepth("maximum derivation depth reached".to_string()))
+}
+
+/// `HMAC-SHA512(key, data)` split into its left and right 32-byte halves.
+fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> ([u8; 32], [u8; 32]) {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts any key length");
+    for chunk in data {
+        mac.update(chunk);
+    }
+    let out = mac.finalize().into_bytes();
+
+    let (mut left, mut right) = ([0u8; 32], [0u8; 32]);
+    left.copy_from_slice(&out[..32]);
+    right.copy_from_slice(&out[32..]);
+    (left, right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEED: &str = "000102030405060708090a0b0c0d0e0f";
+
+    fn assert_key(key: &ExtendedPrivKey, chain_code: &str, secret: &str, public: &str) {
+        assert_eq!(hex::encode(&key.chain_code), chain_code);
+        assert_eq!(hex::encode(&key.private_key.secret().to_bytes()), secret);
+        assert_eq!(
+            hex::encode(&key.private_key.public_key().unwrap().to_sec_compressed()),
+            public
+        );
+    }
+
+    #[test]
+    fn test_vector_1() {
+        let m = ExtendedPrivKey::new_master(&hex::decode(SEED).unwrap()).unwrap();
+        assert_key(
+            &m,
+            "873dff81c02f525623fd1fe5167eac3a55a049de3d314bb42ee227ffed37d508",
+            "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35",
+            "0339a36013301597daef41fbe593a02cc513d0b55527ec2df1050e2e8ff49c85c2",
+        );
+
+        let m_0h = m.derive_child(HARDENED).unwrap();
+        assert_key(
+            &m_0h,
+            "47fdacbd0f1097043b78c63c20c34ef4ed9a111d980047ad16282c7ae6236141",
+            "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea",
+            "035a784662a4a20a65bf6aab9ae98a6c068a81c52e4b032c0fb5400c706cfccc56",
+        );
+        assert_eq!(
+            (m_0h.depth, m_0h.parent_fingerprint, m_0h.child_number),
+            (1, [0x34, 0x42, 0x19, 0x3e], HARDENED)
+        );
+
+        let m_0h_1 = m_0h.derive_child(1).unwrap();
+        assert_key(
+            &m_0h_1,
+            "2a7857631386ba23dacac34180dd1983734e444fdbf774041578e9b6adb37c19",
+            "3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368",
+            "03501e454bf00751f24b1b489aa925215d66af2234e3891c3b21a52bedb3cd711c",
+        );
+
+        let m_0h_1_2h = m_0h_1.derive_child(HARDENED + 2).unwrap();
+        assert_key(
+            &m_0h_1_2h,
+            "04466b9cc8e161e966409ca52986c584f07e9dc81f735db683c3ff6ec7b1503f",
+            "cbce0d719ecf7431d88e6a89fa1483e02e35092af60c042b1df2ff59fa424dca",
+            "0357bfe1e341d01c69fe5654309956cbea516822fba8a601743a012a7896ee8dc2",
+        );
+        assert_eq!(m_0h_1_2h.depth, 3);
+    }
+
+    #[test]
+    fn public_derivation_matches_private() {
+        let m = ExtendedPrivKey::new_master(&hex::decode(SEED).unwrap()).unwrap();
+        let m_0h = m.derive_child(HARDENED).unwrap();
+
+        let from_public = m_0h.to_extended_pub().unwrap().derive_child(1).unwrap();
+        let from_private = m_0h.derive_child(1).unwrap().to_extended_pub().unwrap();
+        assert_eq!(from_public, from_private);
+    }
+
+    #[test]
+    fn public_derivation_rejects_hardened_index() {
+        let m = ExtendedPrivKey::new_master(&hex::decode(SEED).unwrap()).unwrap();
+        let xpub = m.to_extended_pub().unwrap();
+        assert!(matches!(
+            xpub.derive_child(HARDENED),
+            Err(Bip32Error::HardenedFromPublic(_))
+        ));
+    }
+
+    #[test]
+    fn derivation_stops_at_max_depth() {
+        let mut m = ExtendedPrivKey::new_master(&hex::decode(SEED).unwrap()).unwrap();
+        m.depth = u8::MAX;
+        assert!(matches!(m.derive_child(0), Err(Bip32Error::MaxDepth(_))));
+
+        let mut xpub = m.to_extended_pub().unwrap();
+        xpub.depth = u8::MAX;
+        assert!(matches!(xpub.derive_child(0), Err(Bip32Error::MaxDepth(_))));
+    }
+
+    #[test]
+    fn base58_test_vector_1() {
+        // (path, xprv, xpub)
+        let vectors = [
+            (
+                &[][..],
+                "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi",
+                "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8",
+            ),
+            (
+                &[HARDENED][..],
+                "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11eZG7XnxHrnYeSvkzY7d2bhkJ7",
+                "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw",
+            ),
+            (
+                &[HARDENED, 1][..],
+                "xprv9wTYmMFdV23N2TdNG573QoEsfRrWKQgWeibmLntzniatZvR9BmLnvSxqu53Kw1UmYPxLgboyZQaXwTCg8MSY3H2EU4pWcQDnRnrVA1xe8fs",
+                "xpub6ASuArnXKPbfEwhqN6e3mwBcDTgzisQN1wXN9BJcM47sSikHjJf3UFHKkNAWbWMiGj7Wf5uMash7SyYq527Hqck2AxYysAA7xmALppuCkwQ",
+            ),
+        ];
+
+        let m = ExtendedPrivKey::new_master(&hex::decode(SEED).unwrap()).unwrap();
+        for (path, xprv, xpub) in vectors {
+            let key = path
+                .iter()
+                .fold(m.clone(), |key, index| key.derive_child(*index).unwrap());
+            assert_eq!(key.to_base58(false), xprv);
+            assert_eq!(key.to_extended_pub().unwrap().to_base58(false), xpub);
+
+            let (parsed, testnet) = ExtendedPrivKey::from_base58(xprv).unwrap();
+            assert!(!testnet);
+            assert_eq!(parsed.to_base58(false), xprv);
+            assert_eq!(parsed.private_key.secret(), key.private_key.secret());
+
+            let (parsed, testnet) = ExtendedPubKey::from_base58(xpub).unwrap();
+            assert!(!testnet);
+            assert_eq!(parsed, key.to_extended_pub().unwrap());
+        }
+    }
+
+    #[test]
+    fn base58_testnet_round_trip() {
+        let m = ExtendedPrivKey::new_master(&hex::decode(SEED).unwrap()).unwrap();
+        let child = m.derive_child(HARDENED).unwrap();
+
+        let tprv = child.to_base58(true);
+        assert!(tprv.starts_with("tprv"));
+        let (parsed, testnet) = ExtendedPrivKey::from_base58(&tprv).unwrap();
+        assert!(testnet);
+        assert_eq!(parsed.to_base58(true), tprv);
+
+        let xpub = child.to_extended_pub().unwrap();
+        let tpub = xpub.to_base58(true);
+        assert!(tpub.starts_with("tpub"));
+        assert_eq!(ExtendedPubKey::from_base58(&tpub).unwrap(), (xpub, true));
+    }
+
+    #[test]
+    fn base58_rejects_bad_input() {
+        let m = ExtendedPrivKey::new_master(&hex::decode(SEED).unwrap()).unwrap();
+        let xprv = m.to_base58(false);
+        let xpub = m.to_extended_pub().unwrap().to_base58(false);
+
+        // each parser only takes its own kind of key
+        assert!(matches!(
+            ExtendedPrivKey::from_base58(&xpub),
+            Err(EncodingError::InvalidVersion(_))
+        ));
+        assert!(matches!(
+            ExtendedPubKey::from_base58(&xprv),
+            Err(EncodingError::InvalidVersion(_))
+        ));
+
+        let mut tampered = xprv.clone().into_bytes();
+        tampered[20] = if tampered[20] == b'a' { b'b' } else { b'a' };
+        assert!(matches!(
+            ExtendedPrivKey::from_base58(&String::from_utf8(tampered).unwrap()),
+            Err(EncodingError::ChecksumMismatch(_))
+        ));
+
+        let payload = base58check_decode(&xprv).unwrap();
+        assert!(matches!(
+            ExtendedPrivKey::from_base58(&base58check_encode(&payload[..77])),
+            Err(EncodingError::InvalidLength(_))
+        ));
+
+        // depth 0 with a child number set
+        let mut orphan = payload.clone();
+        orphan[12] = 1;
+        assert!(matches!(
+            ExtendedPrivKey::from_base58(&base58check_encode(&orphan)),
+            Err(EncodingError::InvalidPayload(_))
+        ));
+
+        // private key data must be 0x00 || secret
+        let mut bad_prefix = payload;
+        bad_prefix[45] = 0x01;
+        assert!(matches!(
+            ExtendedPrivKey::from_base58(&base58check_encode(&bad_prefix)),
+            Err(EncodingError::InvalidPayload(_))
+        ));
+    }
+
+    #[test]
+    fn debug_redacts_secret() {
+        let m = ExtendedPrivKey::new_master(&hex::decode(SEED).unwrap()).unwrap();
+        let debug = format!("{:?}", m);
+        assert!(!debug.contains(&hex::encode(&m.private_key.secret().to_bytes())));
+        assert!(debug.contains("<redacted>"));
+    }
+}
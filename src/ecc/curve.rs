@@ -0,0 +1,312 @@
+//! ECDSA over any short Weierstrass curve with a prime-order generator. The curve only has
+//! to provide its group order and generator; secp256k1 is `sign::<Secp256k1>(...)`.
+
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+use hmac::{Hmac, Mac};
+use num_bigint::{BigInt, Sign};
+use num_traits::{One, Zero};
+use sha2::Sha256;
+
+use crate::ecc::abstractions::FieldElementTrait;
+use crate::ecc::error::FieldElementError;
+use crate::ecc::point::point::Point;
+use crate::ecc::point::s256_point::S256Point;
+use crate::ecc::s256_field::S256Field;
+use crate::ecc::scalar::Scalar;
+use crate::ecc::signature::Signature;
+
+/// Domain parameters ECDSA needs: the field the curve is defined over, a generator `G` and
+/// its prime order `n`.
+pub trait Curve {
+    type Field: FieldElementTrait + Clone;
+
+    fn order() -> BigInt;
+    fn generator() -> Point<Self::Field>;
+    /// Number of curve points divided by `n`.
+    fn cofactor() -> BigInt;
+}
+
+/// secp256k1, `y^2 = x^3 + 7` over `S256Field`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Secp256k1;
+
+impl Curve for Secp256k1 {
+    type Field = S256Field;
+
+    fn order() -> BigInt {
+        S256Point::order().clone()
+    }
+
+    fn generator() -> Point<S256Field> {
+        S256Point::generator().into()
+    }
+
+    fn cofactor() -> BigInt {
+        BigInt::one()
+    }
+}
+
+/// `h * point` for the cofactor `h` of `C`, mapping any curve point into the subgroup
+/// generated by `G`. The identity on curves with cofactor 1 such as secp256k1.
+pub fn clear_cofactor<C: Curve>(
+    point: &Point<C::Field>,
+) -> Result<Point<C::Field>, FieldElementError> {
+    let h = C::cofactor();
+    if h.is_one() {
+        return Ok(point.clone());
+    }
+    Scalar::new(h) * point
+}
+
+/// Signs message hash `z` with `secret` on curve `C`: `r = (k*G).x`, `s = (z + r*e) / k`,
+/// all mod `n`, with `k` from RFC 6979. The returned signature is low-s normalized.
+pub fn sign<C: Curve>(secret: &Scalar, z: &BigInt) -> Result<Signature, FieldElementError> {
+    let (mut sig, _) = sign_with_nonce_point::<C>(secret, z)?;
+    let n = C::order();
+    if sig.s > &n >> 1 {
+        sig.s = &n - &sig.s;
+    }
+    Ok(sig)
+}
+
+/// [`sign`] without the low-s normalization, also returning the nonce point `R = k*G` that
+/// `r` was taken from. Nonces giving `r = 0` or `s = 0` are skipped for the next one.
+pub(crate) fn sign_with_nonce_point<C: Curve>(
+    secret: &Scalar,
+    z: &BigInt,
+) -> Result<(Signature, Point<C::Field>), FieldElementError> {
+    let n = C::order();
+    let e = secret.get_value();
+    if *e <= BigInt::zero() || *e >= n {
+        return Err(FieldElementError::ScalarOutOfRange(format!(
+            "Secret {} not in range 1 to {}",
+            e,
+            &n - 1
+        )));
+    }
+    let z = reduce_mod(z, &n);
+    let generator = C::generator();
+
+    let mut nonces = Rfc6979::new(e, &z, &n);
+    loop {
+        let k = nonces.next_k();
+        let big_r = (Scalar::new(k.clone()) * &generator)?;
+        let r = match &big_r.x {
+            Some(x) => x.get_num() % &n,
+            None => continue,
+        };
+        if r.is_zero() {
+            continue;
+        }
+
+        let s = (&z + &r * e) * inverse_mod(&k, &n) % &n;
+        if s.is_zero() {
+            continue;
+        }
+        return Ok((Signature::new_unchecked(r, s), big_r));
+    }
+}
+
+/// Verifies an ECDSA signature of `z` against `public_key` on curve `C`: with `u = z/s` and
+/// `v = r/s` (mod `n`) the signature is valid when `(u*G + v*P).x == r (mod n)`.
+pub fn verify<C: Curve>(
+    public_key: &Point<C::Field>,
+    z: &BigInt,
+    sig: &Signature,
+) -> Result<bool, FieldElementError> {
+    let n = C::order();
+    let in_range = |v: &BigInt| *v > BigInt::zero() && *v < n;
+    if !in_range(&sig.r) || !in_range(&sig.s) {
+        return Ok(false);
+    }
+
+    let s_inv = inverse_mod(&sig.s, &n);
+    let u = Scalar::new(reduce_mod(z, &n) * &s_inv % &n);
+    let v = Scalar::new(&sig.r * &s_inv % &n);
+    let total = ((u * &C::generator())? + (v * public_key)?)?;
+
+    match total.x {
+        Some(x) => Ok(x.get_num() % &n == sig.r),
+        None => Ok(false),
+    }
+}
+
+/// `a mod n` in `[0, n)`; `%` alone keeps the sign of a negative `a`. Like
+/// [`Scalar::reduce_mod_n`], but for the order of any curve rather than secp256k1's.
+fn reduce_mod(a: &BigInt, n: &BigInt) -> BigInt {
+    let r = a % n;
+    if r < BigInt::zero() {
+        r + n
+    } else {
+        r
+    }
+}
+
+/// `a^-1 mod n` for prime `n`, by Fermat's little theorem.
+fn inverse_mod(a: &BigInt, n: &BigInt) -> BigInt {
+    a.modpow(&(n - BigInt::from(2u8)), n)
+}
+
+/// RFC 6979 nonce generator (HMAC-SHA256) for a group of order `n`. Successive calls to
+/// [`Rfc6979::next_k`] give the candidates to retry with when a nonce yields `r = 0` or
+/// `s = 0`.
+pub(crate) struct Rfc6979 {
+    k: [u8; 32],
+    v: [u8; 32],
+    qlen: u64,
+    n: BigInt,
+    started: bool,
+}
+
+impl Rfc6979 {
+    pub(crate) fn new(secret: &BigInt, z: &BigInt, n: &BigInt) -> Rfc6979 {
+        let qlen = n.bits();
+        let rlen = qlen.div_ceil(8) as usize;
+        let secret = int_to_octets(secret, rlen);
+        let z = int_to_octets(&(z % n), rlen);
+
+        let mut k = [0u8; 32];
+        let mut v = [1u8; 32];
+        k = hmac_sha256(&k, &[&v, &[0x00], &secret, &z]);
+        v = hmac_sha256(&k, &[&v]);
+        k = hmac_sha256(&k, &[&v, &[0x01], &secret, &z]);
+        v = hmac_sha256(&k, &[&v]);
+
+        Rfc6979 {
+            k,
+            v,
+            qlen,
+            n: n.clone(),
+            started: false,
+        }
+    }
+
+    /// Next nonce in `[1, n)`.
+    pub(crate) fn next_k(&mut self) -> BigInt {
+        loop {
+            if self.started {
+                self.k = hmac_sha256(&self.k, &[&self.v, &[0x00]]);
+                self.v = hmac_sha256(&self.k, &[&self.v]);
+            }
+            self.started = true;
+
+            let mut t = Vec::new();
+            while (t.len() as u64) * 8 < self.qlen {
+                self.v = hmac_sha256(&self.k, &[&self.v]);
+                t.extend_from_slice(&self.v);
+            }
+            // bits2int: keep the leftmost qlen bits
+            let candidate =
+                BigInt::from_bytes_be(Sign::Plus, &t) >> (t.len() as u64 * 8 - self.qlen);
+            if candidate >= BigInt::one() && candidate < self.n {
+                return candidate;
+            }
+        }
+    }
+}
+
+/// Big-endian encoding of a non-negative `value` left-padded to `len` bytes.
+fn int_to_octets(value: &BigInt, len: usize) -> Vec<u8> {
+    let (_, bytes) = value.to_bytes_be();
+    let mut out = vec![0u8; len.saturating_sub(bytes.len())];
+    out.extend_from_slice(&bytes);
+    out
+}
+
+pub(crate) fn hmac_sha256(key: &[u8], data: &[&[u8]]) -> [u8; 32] {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts any key length");
+    for chunk in data {
+        mac.update(chunk);
+    }
+    mac.finalize().into_bytes().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecc::field_element::FieldElement;
+    use crate::ecc::private_key::PrivateKey;
+
+    /// `y^2 = x^3 + 7` over F_223 with `G = (15, 86)` of order 7. The curve has 252
+    /// points, so the cofactor is 36.
+    struct Toy;
+
+    impl Curve for Toy {
+        type Field = FieldElement;
+
+        fn order() -> BigInt {
+            BigInt::from(7u8)
+        }
+
+        fn generator() -> Point<FieldElement> {
+            let fe = |n| FieldElement::new(n, 223).unwrap();
+            Point::new(fe(0), fe(7), Some(fe(15)), Some(fe(86))).unwrap()
+        }
+
+        fn cofactor() -> BigInt {
+            BigInt::from(36u8)
+        }
+    }
+
+    #[test]
+    fn toy_curve_sign_verify() {
+        for e in 1u8..7 {
+            let secret = Scalar::from(e);
+            let public_key = (secret.clone() * &Toy::generator()).unwrap();
+            for z in 0u8..7 {
+                let z = BigInt::from(z);
+                let sig = sign::<Toy>(&secret, &z).unwrap();
+                assert!(sig.s <= BigInt::from(3u8));
+                assert!(verify::<Toy>(&public_key, &z, &sig).unwrap());
+
+                // z and z - n are the same message hash mod n
+                let negative = &z - 7u8;
+                assert_eq!(sign::<Toy>(&secret, &negative).unwrap(), sig);
+                assert!(verify::<Toy>(&public_key, &negative, &sig).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn clear_cofactor_lands_in_subgroup() {
+        let g = Toy::generator();
+        // (47, 71) has order 21, outside the order-7 subgroup
+        let fe = |n| FieldElement::new(n, 223).unwrap();
+        let p = Point::new(fe(0), fe(7), Some(fe(47)), Some(fe(71))).unwrap();
+        assert_eq!(p.order(100), Some(21));
+
+        let cleared = clear_cofactor::<Toy>(&p).unwrap();
+        assert_eq!(cleared.order(100), Some(7));
+        assert!((1u8..7).any(|k| (Scalar::from(k) * &g).unwrap() == cleared));
+
+        let secp_g = Secp256k1::generator();
+        assert_eq!(clear_cofactor::<Secp256k1>(&secp_g).unwrap(), secp_g);
+    }
+
+    #[test]
+    fn toy_curve_rejects_out_of_range_secret() {
+        for e in [0u8, 7] {
+            assert!(matches!(
+                sign::<Toy>(&Scalar::from(e), &BigInt::one()),
+                Err(FieldElementError::ScalarOutOfRange(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn secp256k1_matches_private_key() {
+        let key = PrivateKey::new(Scalar::from(12345u32)).unwrap();
+        let z = BigInt::from_bytes_be(Sign::Plus, &crate::hash::hash256(b"generic signing"));
+
+        let sig = sign::<Secp256k1>(&key.secret(), &z).unwrap();
+        assert_eq!(sig, key.sign_recoverable(&z).unwrap().sig);
+
+        let public_key = key.public_key().unwrap().clone();
+        assert!(verify::<Secp256k1>(&public_key.clone().into(), &z, &sig).unwrap());
+        assert!(public_key.verify(&z, &sig).unwrap());
+        assert!(public_key.verify(&(&z - S256Point::order()), &sig).unwrap());
+        assert!(!verify::<Secp256k1>(&public_key.into(), &(z + 1u8), &sig).unwrap());
+    }
+}
@@ -1,25 +1,188 @@
-use std::fmt;
-use std::fmt::Formatter;
+use alloc::string::String;
+use core::fmt;
+use core::fmt::Formatter;
+
+use num_bigint::BigInt;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum FieldElementError {
-    FieldNotInRange(String),
+    /// `num` is outside `[0, prime)`.
+    FieldNotInRange {
+        num: BigInt,
+        prime: BigInt,
+    },
     InvalidField(String),
-    PointNotOnTheCurve(String),
+    DifferentCurves(String),
+    NotOnCurve(String),
+    InvalidAddition(String),
+    NotQuadraticResidue(String),
+    InvalidEncoding(String),
+    ScalarOutOfRange(String),
+    /// A point that must be finite, as input or as result, is the point at infinity.
+    PointAtInfinity(String),
 }
 
 impl fmt::Display for FieldElementError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
-            FieldElementError::FieldNotInRange(err) => {
-                write!(f, "FieldNotInRange({})", err)
+            FieldElementError::FieldNotInRange { num, prime } => {
+                write!(
+                    f,
+                    "FieldNotInRange(Num {} not in field range 0 to {})",
+                    num,
+                    prime - 1
+                )
             }
             FieldElementError::InvalidField(err) => {
                 write!(f, "InvalidField({})", err)
             }
-            FieldElementError::PointNotOnTheCurve(err) => {
-                write!(f, "PointNotOnTheCurve({})", err)
+            FieldElementError::DifferentCurves(err) => {
+                write!(f, "DifferentCurves({})", err)
+            }
+            FieldElementError::NotOnCurve(err) => {
+                write!(f, "NotOnCurve({})", err)
+            }
+            FieldElementError::InvalidAddition(err) => {
+                write!(f, "InvalidAddition({})", err)
+            }
+            FieldElementError::NotQuadraticResidue(err) => {
+                write!(f, "NotQuadraticResidue({})", err)
+            }
+            FieldElementError::InvalidEncoding(err) => {
+                write!(f, "InvalidEncoding({})", err)
+            }
+            FieldElementError::ScalarOutOfRange(err) => {
+                write!(f, "ScalarOutOfRange({})", err)
+            }
+            FieldElementError::PointAtInfinity(err) => {
+                write!(f, "PointAtInfinity({})", err)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FieldElementError {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SignatureError {
+    InvalidDer(String),
+    InvalidLength(String),
+    HighS(String),
+    ZeroScalar(String),
+    RecoveryFailed(String),
+    ScalarOutOfRange(String),
+    NonCanonical(String),
+}
+
+impl fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SignatureError::InvalidDer(err) => {
+                write!(f, "InvalidDer({})", err)
+            }
+            SignatureError::InvalidLength(err) => {
+                write!(f, "InvalidLength({})", err)
+            }
+            SignatureError::HighS(err) => {
+                write!(f, "HighS({})", err)
+            }
+            SignatureError::ZeroScalar(err) => {
+                write!(f, "ZeroScalar({})", err)
+            }
+            SignatureError::RecoveryFailed(err) => {
+                write!(f, "RecoveryFailed({})", err)
+            }
+            SignatureError::ScalarOutOfRange(err) => {
+                write!(f, "ScalarOutOfRange({})", err)
+            }
+            SignatureError::NonCanonical(err) => {
+                write!(f, "NonCanonical({})", err)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SignatureError {}
+
+/// Failure deriving a BIP32 child key.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Bip32Error {
+    /// Arithmetic on the child key failed, e.g. its secret is zero or its point infinity.
+    Field(FieldElementError),
+    /// A hardened child was requested from an extended public key.
+    HardenedFromPublic(String),
+    /// The parent is already at depth 255.
+    MaxDepth(String),
+}
+
+impl fmt::Display for Bip32Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Bip32Error::Field(err) => {
+                write!(f, "Field({})", err)
+            }
+            Bip32Error::HardenedFromPublic(err) => {
+                write!(f, "HardenedFromPublic({})", err)
+            }
+            Bip32Error::MaxDepth(err) => {
+                write!(f, "MaxDepth({})", err)
+            }
+        }
+    }
+}
+
+impl From<FieldElementError> for Bip32Error {
+    fn from(err: FieldElementError) -> Self {
+        Bip32Error::Field(err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Bip32Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Bip32Error::Field(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Failure aggregating MuSig2 public keys.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MusigError {
+    /// A key or the aggregate is the point at infinity, or the arithmetic failed.
+    Field(FieldElementError),
+    /// The key list is empty.
+    NoKeys(String),
+}
+
+impl fmt::Display for MusigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            MusigError::Field(err) => {
+                write!(f, "Field({})", err)
+            }
+            MusigError::NoKeys(err) => {
+                write!(f, "NoKeys({})", err)
             }
         }
     }
 }
+
+impl From<FieldElementError> for MusigError {
+    fn from(err: FieldElementError) -> Self {
+        MusigError::Field(err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MusigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MusigError::Field(err) => Some(err),
+            MusigError::NoKeys(_) => None,
+        }
+    }
+}
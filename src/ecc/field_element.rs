@@ -1,6 +1,8 @@
-use std::fmt;
-use std::fmt::{Display, Formatter};
-use std::ops::{Add, Div, Mul, Sub};
+use alloc::string::ToString;
+use core::fmt;
+use core::fmt::{Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Div, Mul, Sub};
 
 use num_bigint::BigInt;
 use num_traits::Zero;
@@ -14,7 +16,7 @@ use crate::ecc::error::FieldElementError;
 /// 2. Additive identity; means that 0 exists and has the property a + 0 = a
 /// 3. Multiplicative identity; means 1 exists and has the property a * 1 = a
 /// 4. Additive inverse; means if a is in the set, -a is in the set,
-/// which is defined as the value that makes a + (-a) = 0
+///    which is defined as the value that makes a + (-a) = 0
 #[derive(Debug, Clone)]
 pub struct FieldElement {
     num: BigInt,
@@ -32,11 +34,7 @@ impl FieldElementTrait for FieldElement {
 
     fn from_values(num: BigInt, prime: BigInt) -> Result<FieldElement, FieldElementError> {
         if num >= prime || num < BigInt::zero() {
-            return Err(FieldElementError::FieldNotInRange(format!(
-                "Num {} not in field range 0 to {}",
-                num,
-                prime - 1
-            )));
+            return Err(FieldElementError::FieldNotInRange { num, prime });
         }
 
         Ok(Self { num, prime })
@@ -46,11 +44,10 @@ impl FieldElementTrait for FieldElement {
 impl FieldElement {
     pub fn new(num: i64, prime: i64) -> Result<FieldElement, FieldElementError> {
         if num >= prime || num < 0 {
-            return Err(FieldElementError::FieldNotInRange(format!(
-                "Num {} not in field range 0 to {}",
-                num,
-                prime - 1
-            )));
+            return Err(FieldElementError::FieldNotInRange {
+                num: BigInt::from(num),
+                prime: BigInt::from(prime),
+            });
         }
         Ok(FieldElement {
             num: BigInt::from(num),
@@ -71,6 +68,15 @@ impl PartialEq for FieldElement {
     }
 }
 
+impl Eq for FieldElement {}
+
+impl Hash for FieldElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.num.hash(state);
+        self.prime.hash(state);
+    }
+}
+
 impl Add for FieldElement {
     type Output = ArithmeticResult<FieldElement>;
 
@@ -99,7 +105,7 @@ impl<'a> Add<&'a FieldElement> for FieldElement {
     }
 }
 
-impl<'a, 'b> Add<&'b FieldElement> for &'a FieldElement {
+impl<'b> Add<&'b FieldElement> for &FieldElement {
     type Output = ArithmeticResult<FieldElement>;
 
     fn add(self, rhs: &'b FieldElement) -> Self::Output {
@@ -136,7 +142,7 @@ impl<'a> Sub<&'a FieldElement> for FieldElement {
     type Output = ArithmeticResult<FieldElement>;
 
     fn sub(self, rhs: &'a Self) -> Self::Output {
-        self.check_primes(&rhs)?;
+        self.check_primes(rhs)?;
         let sub = &self.num - rhs.get_num();
         let mut num = sub % &self.prime;
 
@@ -151,7 +157,7 @@ impl<'a> Sub<&'a FieldElement> for FieldElement {
     }
 }
 
-impl<'a, 'b> Sub<&'b FieldElement> for &'a FieldElement {
+impl<'b> Sub<&'b FieldElement> for &FieldElement {
     type Output = ArithmeticResult<FieldElement>;
 
     fn sub(self, rhs: &'b FieldElement) -> Self::Output {
@@ -188,7 +194,7 @@ impl<'a> Mul<&'a FieldElement> for FieldElement {
     type Output = ArithmeticResult<FieldElement>;
 
     fn mul(self, rhs: &'a Self) -> Self::Output {
-        self.check_primes(&rhs)?;
+        self.check_primes(rhs)?;
         let res = &self.num * rhs.get_num();
         let num = res % &self.prime;
         Ok(FieldElement {
@@ -198,11 +204,11 @@ impl<'a> Mul<&'a FieldElement> for FieldElement {
     }
 }
 
-impl<'a, 'b> Mul<&'b FieldElement> for &'a FieldElement {
+impl<'b> Mul<&'b FieldElement> for &FieldElement {
     type Output = ArithmeticResult<FieldElement>;
 
     fn mul(self, rhs: &'b FieldElement) -> Self::Output {
-        self.check_primes(&rhs)?;
+        self.check_primes(rhs)?;
         let res = &self.num * &rhs.num;
         let num = res % &self.prime;
         Ok(FieldElement {
@@ -248,7 +254,7 @@ impl<'a> Div<&'a FieldElement> for FieldElement {
     }
 }
 
-impl<'a, 'b> Div<&'b FieldElement> for &'a FieldElement {
+impl<'b> Div<&'b FieldElement> for &FieldElement {
     type Output = ArithmeticResult<FieldElement>;
 
     fn div(self, rhs: &'b FieldElement) -> Self::Output {
@@ -267,7 +273,10 @@ impl<'a, 'b> Div<&'b FieldElement> for &'a FieldElement {
 }
 
 #[cfg(test)]
+#[allow(clippy::clone_on_copy)]
 mod tests {
+    use num_traits::Pow;
+
     use super::*;
 
     fn new_fe(num: i64, prime: i64) -> FieldElement {
@@ -283,6 +292,31 @@ mod tests {
         assert!((new_fe(2, 31) * new_fe(2, 7)).is_err());
     }
 
+    #[test]
+    fn out_of_range_error_carries_values() {
+        let err = FieldElement::new(5, 3).unwrap_err();
+        assert_eq!(
+            err,
+            FieldElementError::FieldNotInRange {
+                num: BigInt::from(5),
+                prime: BigInt::from(3)
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "FieldNotInRange(Num 5 not in field range 0 to 2)"
+        );
+
+        // the values are there to reduce and retry with
+        let FieldElementError::FieldNotInRange { num, prime } =
+            FieldElement::from_values(BigInt::from(-4), BigInt::from(31)).unwrap_err()
+        else {
+            panic!("expected FieldNotInRange");
+        };
+        let reduced = FieldElement::from_values(((num % &prime) + &prime) % &prime, prime);
+        assert_eq!(reduced.unwrap(), new_fe(27, 31));
+    }
+
     #[test]
     fn equality_test() {
         let prime = 31;
@@ -291,8 +325,7 @@ mod tests {
         let c = new_fe(15, prime.clone());
 
         assert_eq!(a, b);
-        assert_eq!(a != c, true);
-        assert_eq!(a != b, false);
+        assert_ne!(a, c);
     }
 
     #[test]
@@ -320,6 +353,18 @@ mod tests {
         assert_eq!((c - d).unwrap(), new_fe(16, prime));
     }
 
+    #[test]
+    fn neg_test() {
+        let prime = 31;
+        assert_eq!(new_fe(4, prime).neg().unwrap(), new_fe(27, prime));
+        assert_eq!(new_fe(0, prime).neg().unwrap(), new_fe(0, prime));
+        for num in 0..prime {
+            let a = new_fe(num, prime);
+            assert_eq!(a.neg().unwrap().neg().unwrap(), a);
+            assert_eq!((a.clone() + a.neg().unwrap()).unwrap(), new_fe(0, prime));
+        }
+    }
+
     #[test]
     fn mul_test() {
         let prime = 31;
@@ -359,6 +404,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn pow_mod_ref_matches_pow_mod() {
+        let prime = 31;
+        let a = new_fe(17, prime);
+        for e in [-100, -4, -1, 0, 1, 3, 30, 31, 1000] {
+            let exponent = BigInt::from(e);
+            assert_eq!(
+                a.pow_mod_ref(&exponent).unwrap(),
+                a.pow_mod(exponent.clone())
+            );
+        }
+
+        // large negative exponents are reduced rather than stepped towards zero
+        let huge = -BigInt::from(30u8).pow(40u32) - 3;
+        assert_eq!(a.pow_mod_ref(&huge).unwrap(), new_fe(29, prime));
+    }
+
+    #[test]
+    fn square_test() {
+        let prime = 31;
+        for num in [0, 1, 17, 30] {
+            let a = new_fe(num, prime);
+            assert_eq!(a.square().unwrap(), (&a * &a).unwrap());
+        }
+        assert_eq!(new_fe(17, prime).square().unwrap(), new_fe(10, prime));
+    }
+
     #[test]
     fn div_test() {
         let prime = 31;
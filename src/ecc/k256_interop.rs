@@ -0,0 +1,132 @@
+//! Byte-level interop with the RustCrypto `k256` crate, for cross-checking results against
+//! it. No `k256` types are involved: both crates use SEC1 for public keys and DER or
+//! `r || s` for ECDSA signatures, so these are the encodings `k256` reads and writes.
+
+use alloc::vec::Vec;
+
+use crate::ecc::error::{FieldElementError, SignatureError};
+use crate::ecc::point::s256_point::S256Point;
+use crate::ecc::signature::Signature;
+
+impl S256Point {
+    /// Parses the output of `k256::PublicKey::to_sec1_bytes` or `to_encoded_point`, in
+    /// compressed or uncompressed form.
+    pub fn from_k256_sec(bytes: &[u8]) -> Result<S256Point, FieldElementError> {
+        S256Point::parse(bytes)
+    }
+
+    /// Compressed SEC1 encoding, the form `k256::PublicKey::to_sec1_bytes` produces and
+    /// `k256::PublicKey::from_sec1_bytes` accepts.
+    ///
+    /// Panics on the point at infinity, which `k256` can't represent either.
+    pub fn to_k256_sec(&self) -> [u8; 33] {
+        self.to_sec_compressed()
+    }
+}
+
+impl Signature {
+    /// Parses the output of `k256::ecdsa::Signature::to_der`. As in `k256`, `r` and `s`
+    /// must lie in `[1, N)` but a high `s` is accepted.
+    pub fn from_k256_der(bytes: &[u8]) -> Result<Signature, SignatureError> {
+        Signature::from_der(bytes)
+    }
+
+    /// DER encoding readable by `k256::ecdsa::Signature::from_der`.
+    pub fn to_k256_der(&self) -> Vec<u8> {
+        self.to_der()
+    }
+
+    /// Parses the 64-byte `r || s` output of `k256::ecdsa::Signature::to_bytes`.
+    pub fn from_k256_bytes(bytes: &[u8; 64]) -> Result<Signature, SignatureError> {
+        Signature::from_compact(bytes)
+    }
+
+    /// `r || s`, readable by `k256::ecdsa::Signature::from_slice`.
+    pub fn to_k256_bytes(&self) -> [u8; 64] {
+        self.to_compact()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::{BigInt, Sign};
+    use sha2::{Digest, Sha256};
+
+    use super::*;
+    use crate::ecc::private_key::PrivateKey;
+    use crate::encoding::hex;
+
+    // SEC1 public key of the BIP32 test vector 1 master secret, as `k256` encodes it
+    const SECRET: &str = "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35";
+    const SEC_COMPRESSED: &str =
+        "0339a36013301597daef41fbe593a02cc513d0b55527ec2df1050e2e8ff49c85c2";
+    // `k256::ecdsa::SigningKey::sign` of "k256 fixture" with the same secret, as `to_der`
+    // and `to_bytes` write it
+    const DER: &str = "30450221009412294fc413c760b33b75d5d6fcd6283d933f49840dc82eb560c5bbb9d8\
+                       c80902200bdb55f056d0c35c38e4a063631e146d37b4717d3f2e11054c9f32569784142a";
+    const COMPACT: &str = "9412294fc413c760b33b75d5d6fcd6283d933f49840dc82eb560c5bbb9d8c809\
+                           0bdb55f056d0c35c38e4a063631e146d37b4717d3f2e11054c9f32569784142a";
+
+    fn key() -> PrivateKey {
+        PrivateKey::from_bytes(&hex::decode(SECRET).unwrap().try_into().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn sec_matches_k256() {
+        let expected = key().public_key().unwrap().clone();
+        let sec = hex::decode(SEC_COMPRESSED).unwrap();
+
+        let point = S256Point::from_k256_sec(&sec).unwrap();
+        assert_eq!(point, expected);
+        assert_eq!(point.to_k256_sec().to_vec(), sec);
+        assert_eq!(
+            S256Point::from_k256_sec(&expected.to_sec_uncompressed()).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn signature_round_trips() {
+        let z = BigInt::from_bytes_be(Sign::Plus, &crate::hash::hash256(b"k256"));
+        let sig = key().sign(&z).unwrap();
+
+        assert_eq!(Signature::from_k256_der(&sig.to_k256_der()).unwrap(), sig);
+        assert_eq!(
+            Signature::from_k256_bytes(&sig.to_k256_bytes()).unwrap(),
+            sig
+        );
+    }
+
+    #[test]
+    fn signature_encodings_match_k256() {
+        let der = hex::decode(DER).unwrap();
+        let compact: [u8; 64] = hex::decode(COMPACT).unwrap().try_into().unwrap();
+
+        let from_der = Signature::from_k256_der(&der).unwrap();
+        assert_eq!(from_der.to_k256_der(), der);
+        let from_bytes = Signature::from_k256_bytes(&compact).unwrap();
+        assert_eq!(from_bytes.to_k256_bytes(), compact);
+        assert_eq!(from_der, from_bytes);
+
+        // k256 signs SHA-256(msg) with RFC 6979 nonces, which is what `sign` does with that z
+        let z = BigInt::from_bytes_be(Sign::Plus, &Sha256::digest(b"k256 fixture"));
+        assert_eq!(key().sign(&z).unwrap(), from_der);
+    }
+
+    #[test]
+    fn der_rejects_out_of_range_scalars() {
+        let n = S256Point::order().clone();
+        let sig = Signature::new_unchecked(BigInt::from(1u8), n);
+        assert!(Signature::from_k256_der(&sig.to_der()).is_err());
+    }
+
+    #[test]
+    fn der_rejects_oversized_r() {
+        // a 40-byte r is well-formed DER but can't be a scalar
+        let sig = Signature::new_unchecked(BigInt::from(1u8) << 318, BigInt::from(1u8));
+        assert!(matches!(
+            Signature::from_k256_der(&sig.to_der()),
+            Err(SignatureError::ScalarOutOfRange(_))
+        ));
+    }
+}
@@ -0,0 +1,274 @@
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use num_bigint::{BigInt, Sign};
+
+use crate::ecc::error::SignatureError;
+use crate::ecc::point::s256_point::S256Point;
+use crate::ecc::signature::{hash_to_scalar, recover_public_key, RecoverableSignature, Signature};
+use crate::encoding::base58::base58check_decode;
+use crate::encoding::base64;
+use crate::encoding::error::EncodingError;
+use crate::encoding::varint::{decode_varint, encode_varint};
+use crate::hash::hash256;
+
+const MESSAGE_MAGIC: &[u8] = b"\x18Bitcoin Signed Message:\n";
+
+/// `hash256(magic || varint(len) || msg)`, the digest signed by `signmessage`.
+pub fn message_hash(msg: &str) -> [u8; 32] {
+    let mut data = MESSAGE_MAGIC.to_vec();
+    data.extend(encode_varint(msg.len() as u64));
+    data.extend_from_slice(msg.as_bytes());
+    hash256(&data)
+}
+
+/// Verifies a base64 compact signature as produced by `signmessage` against a P2PKH
+/// address. The header byte `27 + recid (+ 4 if compressed)` selects the recovered key
+/// and its SEC form, whose address must match `address`.
+pub fn verify_message(address: &str, sig_base64: &str, msg: &str) -> crate::Result<bool> {
+    let testnet = match base58check_decode(address)?.first() {
+        Some(0x00) => false,
+        Some(0x6f) => true,
+        Some(v) => {
+            return Err(EncodingError::InvalidVersion(format!(
+                "0x{:02x} is not a P2PKH address version",
+                v
+            ))
+            .into())
+        }
+        None => return Err(EncodingError::InvalidLength("empty address".to_string()).into()),
+    };
+
+    let sig = base64::decode(sig_base64)?;
+    if sig.len() != 65 {
+        return Err(SignatureError::InvalidLength(format!(
+            "compact signature is {} bytes, expected 65",
+            sig.len()
+        ))
+        .into());
+    }
+    let header = sig[0];
+    if !(27..=34).contains(&header) {
+        return Err(SignatureError::RecoveryFailed(format!(
+            "header byte {} is not in 27..=34",
+            header
+        ))
+        .into());
+    }
+    let compressed = header >= 31;
+    let rsig = RecoverableSignature {
+        sig: Signature::from_compact(sig[1..].try_into().unwrap())?,
+        recovery_id: (header - 27) & 3,
+    };
+
+    let z = BigInt::from_bytes_be(Sign::Plus, &message_hash(msg));
+    let public_key = recover_public_key(&z, &rsig)?;
+    Ok(public_key.address(compressed, testnet) == address)
+}
+
+/// A message together with its ECDSA signature and the signer's key, as one serializable
+/// unit for verification APIs. The signature is over `hash256(message)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignedMessage {
+    pub pubkey: S256Point,
+    pub signature: Signature,
+    pub message: Vec<u8>,
+}
+
+impl SignedMessage {
+    /// `sec_compressed(pubkey) || compact(signature) || varint(len) || message`.
+    ///
+    /// Panics if `pubkey` is the point at infinity or the signature doesn't fit the compact
+    /// encoding.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = self.pubkey.to_sec_compressed().to_vec();
+        out.extend_from_slice(&self.signature.to_compact());
+        out.extend(encode_varint(self.message.len() as u64));
+        out.extend_from_slice(&self.message);
+        out
+    }
+
+    /// Parses the encoding from [`SignedMessage::to_bytes`]. The message length must account
+    /// for every remaining byte.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<SignedMessage> {
+        if bytes.len() < 33 + 64 {
+            return Err(EncodingError::InvalidLength(format!(
+                "signed message is {} bytes, expected at least 97",
+                bytes.len()
+            ))
+            .into());
+        }
+        let pubkey = S256Point::parse(&bytes[..33])?;
+        let signature = Signature::from_compact(bytes[33..97].try_into().unwrap())?;
+
+        let (len, read) = decode_varint(&bytes[97..])?;
+        let message = &bytes[97 + read..];
+        if message.len() as u64 != len {
+            return Err(EncodingError::InvalidLength(format!(
+                "message length is {} but {} bytes follow",
+                len,
+                message.len()
+            ))
+            .into());
+        }
+
+        Ok(SignedMessage {
+            pubkey,
+            signature,
+            message: message.to_vec(),
+        })
+    }
+
+    /// Whether `signature` is valid for `hash256(message)` under `pubkey`.
+    pub fn verify(&self) -> bool {
+        self.pubkey
+            .verify(&hash_to_scalar(&self.message), &self.signature)
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecc::private_key::PrivateKey;
+    use crate::ecc::scalar::Scalar;
+    use crate::Error;
+
+    const ADDRESS: &str = "15CRxFdyRpGZLW9w8HnHvVduizdL5jKNbs";
+    const SIGNATURE: &str =
+        "IPojfrX2dfPnH26UegfbGQQLrdK844DlHq5157/P6h57WyuS/Qsl+h/WSVGDF4MUi4rWSswW38oimDYfNNUBUOk=";
+
+    fn sign_message(key: &PrivateKey, msg: &str, compressed: bool) -> String {
+        let z = BigInt::from_bytes_be(Sign::Plus, &message_hash(msg));
+        let rsig = key.sign_recoverable(&z).unwrap();
+
+        let mut sig = vec![27 + rsig.recovery_id + if compressed { 4 } else { 0 }];
+        sig.extend_from_slice(&Scalar::new(rsig.sig.r).to_bytes());
+        sig.extend_from_slice(&Scalar::new(rsig.sig.s).to_bytes());
+        base64::encode(&sig)
+    }
+
+    #[test]
+    fn message_hash_vector() {
+        assert_eq!(
+            message_hash("Trust no one"),
+            [
+                0xaa, 0x82, 0x15, 0xd7, 0x23, 0xec, 0xd2, 0xf1, 0x48, 0x67, 0xee, 0xb7, 0xe1, 0x9f,
+                0x19, 0x2b, 0xe7, 0xbc, 0x15, 0xa2, 0x35, 0x2a, 0x24, 0xb9, 0x91, 0xd4, 0xf5, 0x87,
+                0x0c, 0xba, 0xf6, 0xe8
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_real_signed_message() {
+        assert!(verify_message(ADDRESS, SIGNATURE, "Trust no one").unwrap());
+        assert!(!verify_message(ADDRESS, SIGNATURE, "Trust no one.").unwrap());
+        assert!(!verify_message(
+            "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2",
+            SIGNATURE,
+            "Trust no one"
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn sign_and_verify_round_trip() {
+        let key = PrivateKey::new(Scalar::from(12345u32)).unwrap();
+        let public_key = key.public_key().unwrap();
+        let long = "x".repeat(300);
+
+        for msg in ["", "rbtc", long.as_str()] {
+            for compressed in [true, false] {
+                for testnet in [true, false] {
+                    let address = public_key.address(compressed, testnet);
+                    let sig = sign_message(&key, msg, compressed);
+                    assert!(verify_message(&address, &sig, msg).unwrap());
+
+                    // the header's compression flag picks the address that is derived
+                    let other = public_key.address(!compressed, testnet);
+                    assert!(!verify_message(&other, &sig, msg).unwrap());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn verify_rejects_malformed_input() {
+        assert!(matches!(
+            verify_message(ADDRESS, &SIGNATURE[1..], "Trust no one"),
+            Err(Error::Encoding(_))
+        ));
+        assert!(matches!(
+            verify_message(ADDRESS, &base64::encode(&[31u8; 64]), "Trust no one"),
+            Err(Error::Signature(SignatureError::InvalidLength(_)))
+        ));
+
+        let mut sig = base64::decode(SIGNATURE).unwrap();
+        sig[0] = 35;
+        assert!(matches!(
+            verify_message(ADDRESS, &base64::encode(&sig), "Trust no one"),
+            Err(Error::Signature(SignatureError::RecoveryFailed(_)))
+        ));
+
+        // a P2SH address has version 0x05
+        assert!(matches!(
+            verify_message(
+                "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy",
+                SIGNATURE,
+                "Trust no one"
+            ),
+            Err(Error::Encoding(EncodingError::InvalidVersion(_)))
+        ));
+    }
+
+    #[test]
+    fn signed_message_round_trip() {
+        let key = PrivateKey::new(Scalar::from(12345u32)).unwrap();
+        let long = vec![0xabu8; 300];
+
+        for message in [&b""[..], b"rbtc", &long] {
+            let signed = SignedMessage {
+                pubkey: key.public_key().unwrap().clone(),
+                signature: key.sign(&hash_to_scalar(message)).unwrap(),
+                message: message.to_vec(),
+            };
+            let bytes = signed.to_bytes();
+            assert_eq!(
+                bytes.len(),
+                97 + encode_varint(message.len() as u64).len() + message.len()
+            );
+
+            let decoded = SignedMessage::from_bytes(&bytes).unwrap();
+            assert_eq!(decoded, signed);
+            assert!(decoded.verify());
+
+            let mut tampered = decoded.clone();
+            tampered.message.push(0);
+            assert!(!tampered.verify());
+        }
+    }
+
+    #[test]
+    fn signed_message_rejects_bad_lengths() {
+        let key = PrivateKey::new(Scalar::from(12345u32)).unwrap();
+        let bytes = SignedMessage {
+            pubkey: key.public_key().unwrap().clone(),
+            signature: key.sign(&hash_to_scalar(b"rbtc")).unwrap(),
+            message: b"rbtc".to_vec(),
+        }
+        .to_bytes();
+
+        for bad in [
+            &bytes[..96],
+            &bytes[..bytes.len() - 1],
+            &[&bytes[..], &[0]].concat(),
+        ] {
+            assert!(matches!(
+                SignedMessage::from_bytes(bad),
+                Err(Error::Encoding(EncodingError::InvalidLength(_)))
+            ));
+        }
+    }
+}
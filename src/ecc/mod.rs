@@ -1,6 +1,15 @@
-mod field_element;
-mod scalar;
-mod error;
-mod point;
-mod abstractions;
-mod s256_field;
+pub mod abstractions;
+pub mod bip32;
+pub mod curve;
+pub mod error;
+pub mod field_element;
+#[cfg(feature = "k256-interop")]
+pub mod k256_interop;
+pub mod message;
+pub mod musig;
+pub mod point;
+pub mod private_key;
+pub mod s256_field;
+pub mod scalar;
+pub mod schnorr;
+pub mod signature;
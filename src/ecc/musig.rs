@@ -0,0 +1,165 @@
+//! BIP327 (MuSig2) key aggregation. Each key is weighted by a coefficient hashed from the
+//! whole key list, so no signer can pick a key that cancels out the others.
+
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use num_bigint::{BigInt, Sign};
+
+use crate::ecc::error::{FieldElementError, MusigError};
+use crate::ecc::point::s256_point::{sort_pubkeys, S256Point};
+use crate::ecc::scalar::Scalar;
+use crate::ecc::signature::multi_scalar_mul;
+use crate::hash::tagged_hash;
+
+/// Aggregate public key `Q = sum a_i * P_i` of `keys` sorted with [`sort_pubkeys`] (BIP327
+/// `KeyAgg(KeySort(keys))`), so the result doesn't depend on the order keys are given in.
+///
+/// A lone key is not returned unchanged. BIP327's `KeyAggCoeff` gives coefficient 1 only to
+/// the second distinct key, and `GetSecondKey` returns 33 zero bytes when there is none, so a
+/// single key `P` aggregates to `hash_KeyAgg coefficient(L || P) * P`.
+///
+/// Errors when `keys` is empty, contains the point at infinity or sums to infinity.
+pub fn aggregate_pubkeys(keys: &[S256Point]) -> Result<S256Point, MusigError> {
+    let mut sorted = keys.to_vec();
+    sort_pubkeys(&mut sorted);
+    key_agg(&sorted)
+}
+
+/// BIP327 `KeyAgg` over `keys` in the given order. With `L = hash_KeyAgg list(pk_1 || ... ||
+/// pk_u)`, key `i` gets `a_i = hash_KeyAgg coefficient(L || pk_i) mod N`, except that the
+/// first key differing from `pk_1` gets `a_i = 1`.
+fn key_agg(keys: &[S256Point]) -> Result<S256Point, MusigError> {
+    if keys.is_empty() {
+        return Err(MusigError::NoKeys("no keys to aggregate".to_string()));
+    }
+    if keys.iter().any(|key| key.x().is_none()) {
+        return Err(FieldElementError::PointAtInfinity(
+            "cannot aggregate the point at infinity".to_string(),
+        )
+        .into());
+    }
+
+    let secs: Vec<[u8; 33]> = keys.iter().map(S256Point::to_sec_compressed).collect();
+    let second = secs.iter().find(|sec| **sec != secs[0]);
+    let list_hash = tagged_hash("KeyAgg list", &secs.concat());
+
+    let terms: Vec<(Scalar, S256Point)> = secs
+        .iter()
+        .zip(keys)
+        .map(|(sec, key)| {
+            let coefficient = if Some(sec) == second {
+                Scalar::from(1u8)
+            } else {
+                let hash = tagged_hash("KeyAgg coefficient", &[&list_hash[..], sec].concat());
+                Scalar::reduce_mod_n(BigInt::from_bytes_be(Sign::Plus, &hash))
+            };
+            (coefficient, key.clone())
+        })
+        .collect();
+
+    let aggregate = multi_scalar_mul(&terms)?;
+    if aggregate.x().is_none() {
+        return Err(
+            FieldElementError::PointAtInfinity("aggregate key is infinity".to_string()).into(),
+        );
+    }
+    Ok(aggregate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::hex;
+
+    fn key(sec: &str) -> S256Point {
+        S256Point::parse(&hex::decode(sec).unwrap()).unwrap()
+    }
+
+    fn keys() -> [S256Point; 3] {
+        [
+            key("02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9"),
+            key("03dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659"),
+            key("023590a94e768f8e1815c2f24b4d80a8e3149316c3518ce7b7ad338368d038ca66"),
+        ]
+    }
+
+    #[test]
+    fn key_agg_vectors() {
+        // BIP327 key_agg_vectors.json, x-only aggregate keys
+        let vectors = [
+            (
+                &[0, 1, 2][..],
+                "90539eede565f5d054f32cc0c220126889ed1e5d193baf15aef344fe59d4610c",
+            ),
+            (
+                &[2, 1, 0][..],
+                "6204de8b083426dc6eaf9502d27024d53fc826bf7d2012148a0575435df54b2b",
+            ),
+            (
+                &[0, 0, 0][..],
+                "b436e3bad62b8cd409969a224731c193d051162d8c5ae8b109306127da3aa935",
+            ),
+            (
+                &[0, 0, 1, 1][..],
+                "69bc22bfa5d106306e48a20679de1d7389386124d07571d0d872686028c26a3e",
+            ),
+        ];
+
+        let keys = keys();
+        for (indices, expected) in vectors {
+            let selected: Vec<S256Point> = indices.iter().map(|i| keys[*i].clone()).collect();
+            let aggregate = key_agg(&selected).unwrap();
+            assert_eq!(hex::encode(&aggregate.to_x_only()), expected);
+        }
+    }
+
+    #[test]
+    fn aggregate_is_order_independent() {
+        let [a, b, c] = keys();
+        let expected = aggregate_pubkeys(&[a.clone(), b.clone(), c.clone()]).unwrap();
+        for order in [
+            [b.clone(), a.clone(), c.clone()],
+            [c.clone(), b.clone(), a.clone()],
+            [a.clone(), c.clone(), b.clone()],
+        ] {
+            assert_eq!(aggregate_pubkeys(&order).unwrap(), expected);
+        }
+        assert_eq!(key_agg(&[c, a, b]).unwrap(), expected);
+    }
+
+    /// Hashed coefficient of `key` within `list`.
+    fn coefficient(list: &[&S256Point], key: &S256Point) -> Scalar {
+        let secs: Vec<[u8; 33]> = list.iter().map(|k| k.to_sec_compressed()).collect();
+        let list_hash = tagged_hash("KeyAgg list", &secs.concat());
+        let hash = tagged_hash(
+            "KeyAgg coefficient",
+            &[&list_hash[..], &key.to_sec_compressed()].concat(),
+        );
+        Scalar::reduce_mod_n(BigInt::from_bytes_be(Sign::Plus, &hash))
+    }
+
+    #[test]
+    fn second_key_has_coefficient_one() {
+        // the first key differing from pk_1 enters the sum as itself, pk_1 with a hashed
+        // coefficient
+        let [a, b, _] = keys();
+        let expected = (&(&a * &coefficient(&[&a, &b], &a)).unwrap() + &b).unwrap();
+        assert_eq!(key_agg(&[a.clone(), b]).unwrap(), expected);
+
+        // not the key itself: with no second distinct key, KeyAggCoeff hashes its coefficient
+        let single = aggregate_pubkeys(core::slice::from_ref(&a)).unwrap();
+        assert_ne!(single, a);
+        assert_eq!(single, (&a * &coefficient(&[&a], &a)).unwrap());
+    }
+
+    #[test]
+    fn aggregate_rejects_degenerate_input() {
+        assert!(matches!(aggregate_pubkeys(&[]), Err(MusigError::NoKeys(_))));
+        let infinity = S256Point::new(None, None).unwrap();
+        assert!(matches!(
+            aggregate_pubkeys(&[keys()[0].clone(), infinity]),
+            Err(MusigError::Field(FieldElementError::PointAtInfinity(_)))
+        ));
+    }
+}
@@ -0,0 +1,193 @@
+//! GLV scalar multiplication. secp256k1 has the endomorphism `λ·(x, y) = (β·x, y)` with
+//! `β^3 = 1 (mod p)` and `λ^3 = 1 (mod N)`, so `k·P` can be rewritten as `k1·P + k2·λ(P)`
+//! with `k1`, `k2` of about 128 bits each, halving the number of doublings.
+
+use alloc::boxed::Box;
+
+use num_bigint::BigInt;
+use num_traits::{Num, One, Signed};
+use once_cell::race::OnceBox;
+
+use crate::ecc::abstractions::FieldElementTrait;
+use crate::ecc::error::FieldElementError;
+use crate::ecc::point::s256_point::S256Point;
+use crate::ecc::s256_field::S256Field;
+use crate::ecc::scalar::Scalar;
+
+struct GlvConstants {
+    beta: S256Field,
+    lambda: BigInt,
+    // short lattice basis (a1, b1), (a2, b2) with a + b*λ = 0 (mod N)
+    a1: BigInt,
+    b1: BigInt,
+    a2: BigInt,
+    b2: BigInt,
+}
+
+static GLV: OnceBox<GlvConstants> = OnceBox::new();
+
+fn constants() -> &'static GlvConstants {
+    GLV.get_or_init(|| {
+        let hex = |s: &str| BigInt::from_str_radix(s, 16).unwrap();
+        let a1 = hex("3086d221a7d46bcde86c90e49284eb15");
+        Box::new(GlvConstants {
+            beta: S256Field::new(hex(
+                "7ae96a2b657c07106e64479eac3434e99cf0497512f58995c1396c28719501ee",
+            ))
+            .unwrap(),
+            lambda: hex("5363ad4cc05c30e0a5261c028812645a122e22ea20816678df02967c1b23bd72"),
+            b1: -hex("e4437ed6010e88286f547fa90abfe4c3"),
+            a2: hex("114ca50f7a8e2f3f657c1108d9d44cfd8"),
+            b2: a1.clone(),
+            a1,
+        })
+    })
+}
+
+/// `round(a / n)` for non-negative `n`.
+fn div_round(a: &BigInt, n: &BigInt) -> BigInt {
+    let half: BigInt = n >> 1;
+    let num = a + half;
+    if num.is_negative() {
+        -((-num + n - BigInt::one()) / n)
+    } else {
+        num / n
+    }
+}
+
+/// Splits `k` into `(k1, k2)` with `k = k1 + k2*λ (mod N)` and both halves around 128 bits.
+/// Either half may be negative.
+fn decompose(k: &BigInt) -> (BigInt, BigInt) {
+    let c = constants();
+    let n = S256Point::order();
+
+    let c1 = div_round(&(&c.b2 * k), n);
+    let c2 = div_round(&(-&c.b1 * k), n);
+
+    let k1 = k - &c1 * &c.a1 - &c2 * &c.a2;
+    let k2 = -&c1 * &c.b1 - &c2 * &c.b2;
+    debug_assert_eq!(
+        Scalar::reduce_mod_n(&k1 + &k2 * &c.lambda).n,
+        Scalar::reduce_mod_n(k.clone()).n
+    );
+    (k1, k2)
+}
+
+fn negate(p: &S256Point) -> Result<S256Point, FieldElementError> {
+    match (p.x(), p.y()) {
+        (Some(x), Some(y)) => S256Point::new(Some(x.clone()), Some(y.neg()?)),
+        _ => Ok(p.clone()),
+    }
+}
+
+impl S256Point {
+    /// `λ·self = (β·x, y)`, a single field multiplication.
+    fn endomorphism(&self) -> Result<S256Point, FieldElementError> {
+        match (self.x(), self.y()) {
+            (Some(x), Some(y)) => S256Point::new(Some((&constants().beta * x)?), Some(y.clone())),
+            _ => Ok(self.clone()),
+        }
+    }
+
+    /// `scalar * self` via the GLV decomposition `k1·P + k2·λ(P)`, evaluated with Shamir's
+    /// trick so both halves share one ~128-step double-and-add. Gives the same result as
+    /// `scalar * &point`, reduced mod `N`.
+    pub fn mul_glv(&self, scalar: &Scalar) -> Result<S256Point, FieldElementError> {
+        let k = Scalar::reduce_mod_n(scalar.n.clone()).n;
+        let (k1, k2) = decompose(&k);
+
+        // fold the signs into the points so both coefficients are non-negative
+        let p1 = if k1.is_negative() {
+            negate(self)?
+        } else {
+            self.clone()
+        };
+        let lambda_p = self.endomorphism()?;
+        let p2 = if k2.is_negative() {
+            negate(&lambda_p)?
+        } else {
+            lambda_p
+        };
+        let (k1, k2) = (k1.abs(), k2.abs());
+        let both = (&p1 + &p2)?;
+
+        let mut result = S256Point::new(None, None)?;
+        for i in (0..k1.bits().max(k2.bits())).rev() {
+            result = (&result + &result)?;
+            match (k1.bit(i), k2.bit(i)) {
+                (true, true) => result = (&result + &both)?,
+                (true, false) => result = (&result + &p1)?,
+                (false, true) => result = (&result + &p2)?,
+                (false, false) => {}
+            }
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::Sign;
+
+    use super::*;
+    use crate::ecc::abstractions::FieldElementTrait;
+    use crate::hash::hash256;
+
+    /// Deterministic stand-in for random scalars.
+    fn scalars(count: u8) -> Vec<Scalar> {
+        (0..count)
+            .map(|i| Scalar::new(BigInt::from_bytes_be(Sign::Plus, &hash256(&[i]))))
+            .collect()
+    }
+
+    #[test]
+    fn constants_are_cube_roots_of_unity() {
+        let c = constants();
+        let n = S256Point::order();
+        assert_eq!(c.lambda.modpow(&BigInt::from(3u8), n), BigInt::one());
+        assert_eq!(
+            c.beta
+                .get_num()
+                .modpow(&BigInt::from(3u8), S256Field::prime()),
+            BigInt::one()
+        );
+
+        let g = S256Point::generator();
+        assert_eq!(
+            g.endomorphism().unwrap(),
+            (Scalar::new(c.lambda.clone()) * &g).unwrap()
+        );
+    }
+
+    #[test]
+    fn decompose_splits_into_short_halves() {
+        let c = constants();
+        let n = S256Point::order();
+        for k in scalars(16) {
+            let k = Scalar::reduce_mod_n(k.n).n;
+            let (k1, k2) = decompose(&k);
+            assert!(k1.bits() <= 129 && k2.bits() <= 129);
+
+            let recombined = Scalar::reduce_mod_n(&k1 + &k2 * &c.lambda).n;
+            assert_eq!(recombined, k % n);
+        }
+    }
+
+    #[test]
+    fn mul_glv_matches_double_and_add() {
+        let g = S256Point::generator();
+        let p = (Scalar::from(1485u32) * &g).unwrap();
+
+        let mut ks = scalars(8);
+        ks.extend([
+            Scalar::from(0u8),
+            Scalar::from(1u8),
+            Scalar::new(constants().lambda.clone()),
+            Scalar::new(S256Point::order() - BigInt::from(2u8)),
+        ]);
+        for k in ks {
+            assert_eq!(g.mul_glv(&k).unwrap(), (k.clone() * &g).unwrap());
+            assert_eq!(p.mul_glv(&k).unwrap(), (k.clone() * &p).unwrap());
+        }
+    }
+}
@@ -1,2 +1,4 @@
-mod point;
-mod s256_point;
\ No newline at end of file
+pub mod glv;
+#[allow(clippy::module_inception)]
+pub mod point;
+pub mod s256_point;
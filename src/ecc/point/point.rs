@@ -1,16 +1,17 @@
-use std::fmt::{Display, Formatter};
-use std::ops::{Add, Mul};
+use alloc::format;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::iter;
+use core::ops::{Add, AddAssign, Mul};
 
 use num_bigint::BigInt;
-use num_traits::Zero;
+use num_traits::{One, Zero};
 
 use crate::ecc::abstractions::FieldElementTrait;
 use crate::ecc::error::FieldElementError;
-use crate::ecc::field_element::FieldElement;
 use crate::ecc::scalar::Scalar;
 
-// use crate::ecc::scalar::Scalar;
-
 #[derive(Debug, Clone)]
 pub struct Point<F: FieldElementTrait + Clone> {
     pub a: F,
@@ -29,27 +30,22 @@ impl<F: FieldElementTrait + Clone> Point<F> {
                 y: None,
             });
         }
-        let y_squared = &y
-            .as_ref()
-            .map(|y| {
-                let exp = BigInt::from(2u8);
-                y.pow_mod(exp)
-            })
-            .unwrap();
+        let y_squared = &y.as_ref().map(|y| y.square()).unwrap()?;
 
         let equation = &x
             .as_ref()
             .map(|x_value| {
                 // x^3 + ax + b
-                let exp = BigInt::from(3u8);
                 let ax = a.clone().mul(x_value)?;
-                x_value.pow_mod(exp).add(ax)?.add(b.clone())
+                x_value
+                    .pow_mod_ref(&BigInt::from(3u8))?
+                    .add(ax)?
+                    .add(b.clone())
             })
-            .unwrap()
-            .expect(format!("x={} is not on the curve", x.clone().unwrap()).as_str());
+            .unwrap()?;
 
         if y_squared != equation {
-            return Err(FieldElementError::PointNotOnTheCurve(format!(
+            return Err(FieldElementError::NotOnCurve(format!(
                 "({}, {}) is not on the curve",
                 x.unwrap(),
                 y.unwrap()
@@ -59,22 +55,102 @@ impl<F: FieldElementTrait + Clone> Point<F> {
         Ok(Self { a, b, x, y })
     }
 
+    /// Every point on `y^2 = x^3 + ax + b` over the field of `a` and `b`, the point at infinity
+    /// first. Each `x` in `[0, p)` is tried in turn and `y` solved for with Tonelli-Shanks, so
+    /// this walks the whole field and is only practical for small teaching primes like 223.
+    pub fn all_points(a: F, b: F) -> impl Iterator<Item = Point<F>> {
+        let prime = a.get_prime().clone();
+        let infinity = Point {
+            a: a.clone(),
+            b: b.clone(),
+            x: None,
+            y: None,
+        };
+
+        let xs = iter::successors(Some(BigInt::zero()), |x| Some(x + 1u8))
+            .take_while(move |x| *x < prime);
+        iter::once(infinity).chain(xs.flat_map(move |x| {
+            let x = F::from_values(x, a.get_prime().clone()).expect("x is below p");
+            let ys = match Point::y_from_x(&a, &b, &x) {
+                Err(_) => Vec::new(),
+                Ok((y, neg)) if y == neg => Vec::from([y]),
+                Ok((y, neg)) if y.get_num() < neg.get_num() => Vec::from([y, neg]),
+                Ok((y, neg)) => Vec::from([neg, y]),
+            };
+
+            ys.into_iter()
+                .map(|y| Point {
+                    a: a.clone(),
+                    b: b.clone(),
+                    x: Some(x.clone()),
+                    y: Some(y),
+                })
+                .collect::<Vec<_>>()
+        }))
+    }
+
+    /// Both square roots `(y, -y)` of `x^3 + ax + b`, the y-coordinates of the points with
+    /// this `x`. They coincide when `y = 0`. Errors with
+    /// [`FieldElementError::NotQuadraticResidue`] when no point on the curve has this `x`.
+    pub fn y_from_x(a: &F, b: &F, x: &F) -> Result<(F, F), FieldElementError> {
+        // x^3 + ax + b
+        let rhs = ((x.pow_mod_ref(&BigInt::from(3u8))? + (a.clone() * x)?)? + b)?;
+        let p = rhs.get_prime();
+        let y = sqrt_mod(rhs.get_num(), p).ok_or_else(|| {
+            FieldElementError::NotQuadraticResidue(format!("{} has no square root", rhs.get_num()))
+        })?;
+        let y = F::from_values(y, p.clone())?;
+        let neg = y.neg()?;
+        Ok((y, neg))
+    }
+
+    /// Smallest `n >= 1` with `n * self` at infinity, found by adding the point to itself.
+    /// Gives up with `None` after `max` steps; only practical for small teaching curves.
+    pub fn order(&self, max: u64) -> Option<u64> {
+        let mut multiple = self.clone();
+        let mut n = 1;
+        while multiple.x.is_some() {
+            if n >= max {
+                return None;
+            }
+            multiple = (&multiple + self).ok()?;
+            n += 1;
+        }
+        Some(n)
+    }
+
+    /// `self = self + other`, for accumulating sums without rebinding.
+    pub fn try_add_assign(&mut self, other: &Self) -> Result<(), FieldElementError> {
+        *self = (&*self + other)?;
+        Ok(())
+    }
+
     fn is_additive_inverse(&self, other: &Self) -> bool {
         self.x == other.x && self.y != other.y
     }
 
     fn is_on_vertical_line(&self) -> bool {
         if let Some(y1) = &self.y {
-            if let Some(_) = &self.x {
+            if self.x.is_some() {
                 return *y1.get_num() == BigInt::zero();
             }
         }
         false
     }
 
+    /// Errors with [`FieldElementError::InvalidField`] unless `a`, `b` and the coordinates
+    /// all belong to the same field.
+    fn check_same_field(&self) -> Result<(), FieldElementError> {
+        self.a.check_primes(&self.b)?;
+        for coordinate in [&self.x, &self.y].into_iter().flatten() {
+            self.a.check_primes(coordinate)?;
+        }
+        Ok(())
+    }
+
     fn check_points_on_the_curve(&self, other: &Self) -> Result<(), FieldElementError> {
-        if self.a != other.a && self.b != other.b {
-            return Err(FieldElementError::PointNotOnTheCurve(format!(
+        if self.a != other.a || self.b != other.b {
+            return Err(FieldElementError::DifferentCurves(format!(
                 "Points {}, {} are not on the same curve",
                 self, other
             )));
@@ -85,16 +161,23 @@ impl<F: FieldElementTrait + Clone> Point<F> {
 
 impl<F: FieldElementTrait + Clone> PartialEq for Point<F> {
     fn eq(&self, other: &Self) -> bool {
-        &self.x == &other.x && &self.y == &other.y && &self.a == &other.a && self.b == other.b
+        self.x == other.x && self.y == other.y && self.a == other.a && self.b == other.b
     }
+}
 
-    fn ne(&self, other: &Self) -> bool {
-        self != other
+impl<F: FieldElementTrait + Clone + Eq> Eq for Point<F> {}
+
+impl<F: FieldElementTrait + Clone + Hash> Hash for Point<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.y.hash(state);
+        self.a.hash(state);
+        self.b.hash(state);
     }
 }
 
 impl<F: FieldElementTrait + Clone> Display for Point<F> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         if self.x.is_none() {
             return write!(f, "Point(infinity)");
         }
@@ -106,7 +189,7 @@ impl<F: FieldElementTrait + Clone> Display for Point<F> {
             .map(|v| format!("FieldElement_{}({})", v.get_num(), v.get_prime()))
             .unwrap_or_else(|| format!("FieldElement_None({})", prime));
 
-        return write!(
+        write!(
             f,
             "Point({}, {})_{}_{} FieldElement({})",
             self.x.as_ref().unwrap(),
@@ -114,7 +197,7 @@ impl<F: FieldElementTrait + Clone> Display for Point<F> {
             &self.a,
             &self.b,
             prime,
-        );
+        )
     }
 }
 
@@ -134,9 +217,9 @@ impl<F: FieldElementTrait + Clone> Add for Point<F> {
             return Ok(self);
         }
 
-        // Same x but different y (Additive inverse)
+        // Same x but different y (Additive inverse), P + (-P) is the point at infinity
         if self.is_additive_inverse(&other) {
-            return Point::new(self.a, self.b, self.x, self.y);
+            return Point::new(self.a, self.b, None, None);
         }
 
         // Different x
@@ -149,7 +232,7 @@ impl<F: FieldElementTrait + Clone> Add for Point<F> {
             let slope = (y2.clone().sub(y1)? / x2.clone().sub(x1)?)?;
 
             // x3 = slope^2 - x1 - x2
-            let x3 = &slope.pow_mod(BigInt::from(2u8)).sub(x1)?.sub(x2)?;
+            let x3 = &slope.square()?.sub(x1)?.sub(x2)?;
 
             // y3 = slope * (x1 - x3) - y1
             let y3 = &slope.mul(x1.clone().sub(x3)?)?.sub(y1)?;
@@ -168,18 +251,14 @@ impl<F: FieldElementTrait + Clone> Add for Point<F> {
             let (x1, y1) = (self.x.as_ref().unwrap(), self.y.as_ref().unwrap());
 
             // 3 * x1^2 + a
-            let quotient =
-                Scalar::from(3u8).mul(&x1.pow_mod(BigInt::from(2)).add(self.a.clone())?)?;
+            let quotient = Scalar::from(3u8).mul(&x1.square()?.add(self.a.clone())?)?;
             // 2 * y1
             let dividend = Scalar::from(2u8).mul(y1)?;
 
             let s = quotient.div(dividend)?;
 
             // x3 = s^2 - 2 * x1
-            let x3 = s
-                .clone()
-                .pow_mod(BigInt::from(2))
-                .sub(Scalar::from(2u8).mul(x1)?)?;
+            let x3 = s.square()?.sub(Scalar::from(2u8).mul(x1)?)?;
 
             // y3 = s * (x1 - x3) - y1
             let y3 = s.mul(x1.clone().sub(&x3)?)?.sub(y1)?;
@@ -187,15 +266,18 @@ impl<F: FieldElementTrait + Clone> Add for Point<F> {
             return Point::new(self.a, self.b, Some(x3.clone()), Some(y3));
         }
 
-        return Err(FieldElementError::PointNotOnTheCurve(format!("Invalid")));
+        Err(FieldElementError::InvalidAddition(format!(
+            "Cannot add {} and {}",
+            self, other
+        )))
     }
 }
 
-impl<'a, 'b, F: FieldElementTrait + Clone> Add<&'b Point<F>> for &'a Point<F> {
+impl<'b, F: FieldElementTrait + Clone> Add<&'b Point<F>> for &Point<F> {
     type Output = Result<Point<F>, FieldElementError>;
 
     fn add(self, other: &'b Point<F>) -> Self::Output {
-        self.check_points_on_the_curve(&other)?;
+        self.check_points_on_the_curve(other)?;
 
         // Self is point at infinity
         if self.x.is_none() {
@@ -207,14 +289,9 @@ impl<'a, 'b, F: FieldElementTrait + Clone> Add<&'b Point<F>> for &'a Point<F> {
             return Ok(self.clone());
         }
 
-        // Same x but different y (Additive inverse)
-        if self.is_additive_inverse(&other) {
-            return Point::new(
-                self.a.clone(),
-                self.b.clone(),
-                self.x.clone(),
-                self.y.clone(),
-            );
+        // Same x but different y (Additive inverse), P + (-P) is the point at infinity
+        if self.is_additive_inverse(other) {
+            return Point::new(self.a.clone(), self.b.clone(), None, None);
         }
 
         // Different x
@@ -227,7 +304,7 @@ impl<'a, 'b, F: FieldElementTrait + Clone> Add<&'b Point<F>> for &'a Point<F> {
             let slope = (y2.clone().sub(y1)? / x2.clone().sub(x1)?)?;
 
             // x3 = slope^2 - x1 - x2
-            let x3 = &slope.pow_mod(BigInt::from(2u8)).sub(x1)?.sub(x2)?;
+            let x3 = &slope.square()?.sub(x1)?.sub(x2)?;
 
             // y3 = slope * (x1 - x3) - y1
             let y3 = &slope.mul(x1.clone().sub(x3)?)?.sub(y1)?;
@@ -251,18 +328,14 @@ impl<'a, 'b, F: FieldElementTrait + Clone> Add<&'b Point<F>> for &'a Point<F> {
             let (x1, y1) = (self.x.as_ref().unwrap(), self.y.as_ref().unwrap());
 
             // 3 * x1^2 + a
-            let quotient =
-                Scalar::from(3u8).mul(&x1.pow_mod(BigInt::from(2)).add(self.a.clone())?)?;
+            let quotient = Scalar::from(3u8).mul(&x1.square()?.add(self.a.clone())?)?;
             // 2 * y1
             let dividend = Scalar::from(2u8).mul(y1)?;
 
             let s = quotient.div(dividend)?;
 
             // x3 = s^2 - 2 * x1
-            let x3 = s
-                .clone()
-                .pow_mod(BigInt::from(2))
-                .sub(Scalar::from(2u8).mul(x1)?)?;
+            let x3 = s.square()?.sub(Scalar::from(2u8).mul(x1)?)?;
 
             // y3 = s * (x1 - x3) - y1
             let y3 = s.mul(x1.clone().sub(&x3)?)?.sub(y1)?;
@@ -270,12 +343,109 @@ impl<'a, 'b, F: FieldElementTrait + Clone> Add<&'b Point<F>> for &'a Point<F> {
             return Point::new(self.a.clone(), self.b.clone(), Some(x3.clone()), Some(y3));
         }
 
-        return Err(FieldElementError::PointNotOnTheCurve(format!("Invalid")));
+        Err(FieldElementError::InvalidAddition(format!(
+            "Cannot add {} and {}",
+            self, other
+        )))
+    }
+}
+
+/// A square root of `w` modulo the odd prime `p` by Tonelli-Shanks, `None` for a non-residue.
+fn sqrt_mod(w: &BigInt, p: &BigInt) -> Option<BigInt> {
+    let one = BigInt::one();
+    if w.is_zero() {
+        return Some(BigInt::zero());
+    }
+    // Euler's criterion
+    let half: BigInt = (p - 1u8) >> 1;
+    if w.modpow(&half, p) != one {
+        return None;
+    }
+
+    // p - 1 = q * 2^s with q odd
+    let mut q: BigInt = p - 1u8;
+    let mut s = 0u32;
+    while !q.bit(0) {
+        q >>= 1;
+        s += 1;
+    }
+    // any quadratic non-residue
+    let minus_one: BigInt = p - 1u8;
+    let mut z = BigInt::from(2u8);
+    while z.modpow(&half, p) != minus_one {
+        z += 1u8;
+    }
+
+    let mut m = s;
+    let mut c = z.modpow(&q, p);
+    let mut t = w.modpow(&q, p);
+    let mut r = w.modpow(&((&q + 1u8) >> 1), p);
+    while t != one {
+        // least i with t^(2^i) = 1
+        let mut i = 0;
+        let mut t2i = t.clone();
+        while t2i != one {
+            t2i = &t2i * &t2i % p;
+            i += 1;
+        }
+        let b = c.modpow(&(BigInt::one() << (m - i - 1)), p);
+        m = i;
+        c = &b * &b % p;
+        t = t * &c % p;
+        r = r * &b % p;
+    }
+    Some(r)
+}
+
+/// Panics when the points are on different curves; use [`Point::try_add_assign`] to get
+/// the error instead.
+impl<'b, F: FieldElementTrait + Clone> AddAssign<&'b Point<F>> for Point<F> {
+    fn add_assign(&mut self, other: &'b Point<F>) {
+        self.try_add_assign(other)
+            .expect("points must be on the same curve");
+    }
+}
+
+impl<F: FieldElementTrait + Clone> Mul<&Point<F>> for Scalar {
+    type Output = Result<Point<F>, FieldElementError>;
+
+    fn mul(self, rhs: &Point<F>) -> Self::Output {
+        // the fields are public, so a point built by hand may mix fields that `Point::new`
+        // would have rejected; catch that here rather than in the middle of the loop
+        rhs.check_same_field()?;
+        let mut result = Point::new(rhs.a.clone(), rhs.b.clone(), None, None)?;
+        if self.n.is_zero() {
+            return Ok(result);
+        }
+
+        let mut coef = self.n;
+        let zero = BigInt::zero();
+        let mut current = rhs.clone();
+
+        while coef > zero {
+            if coef.bit(0) {
+                result = (&result + &current)?;
+            }
+            current = (&current + &current)?;
+            coef >>= 1;
+        }
+
+        Ok(result)
+    }
+}
+
+impl<F: FieldElementTrait + Clone> Mul<Scalar> for Point<F> {
+    type Output = Result<Point<F>, FieldElementError>;
+
+    fn mul(self, rhs: Scalar) -> Self::Output {
+        rhs * &self
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::ecc::field_element::FieldElement;
+
     use super::*;
 
     fn new_fe(num: i64, prime: i64) -> FieldElement {
@@ -285,34 +455,217 @@ mod tests {
     #[test]
     fn test_on_curve() {
         let prime = 223;
-        let a = new_fe(0, prime.clone());
-        let b = new_fe(7, prime.clone());
+        let a = new_fe(0, prime);
+        let b = new_fe(7, prime);
 
         let valid_points = [(192, 105), (17, 56), (1, 193)];
         for v in valid_points {
-            let x = new_fe(v.0, prime.clone());
-            let y = new_fe(v.1, prime.clone());
+            let x = new_fe(v.0, prime);
+            let y = new_fe(v.1, prime);
             let p1 = Point::new(a.clone(), b.clone(), Some(x), Some(y));
-            assert_eq!(p1.is_ok(), true,);
+            assert!(p1.is_ok(),);
         }
 
         let invalid_points = [(200, 119), (42, 99)];
         for i in invalid_points {
-            let x = new_fe(i.0, prime.clone());
-            let y = new_fe(i.1, prime.clone());
-            assert_eq!(
-                Point::new(a.clone(), b.clone(), Some(x), Some(y)).is_err(),
-                true
+            let x = new_fe(i.0, prime);
+            let y = new_fe(i.1, prime);
+            assert!(Point::new(a.clone(), b.clone(), Some(x), Some(y)).is_err());
+        }
+    }
+
+    #[test]
+    fn off_curve_point_is_not_on_curve() {
+        let prime = 223;
+        let a = new_fe(0, prime);
+        let b = new_fe(7, prime);
+
+        let result = Point::new(a, b, Some(new_fe(200, prime)), Some(new_fe(119, prime)));
+        assert!(matches!(result, Err(FieldElementError::NotOnCurve(_))));
+    }
+
+    #[test]
+    fn coordinates_from_another_field_are_an_error() {
+        let a = new_fe(0, 223);
+        let b = new_fe(7, 223);
+
+        let result = Point::new(a, b, Some(new_fe(3, 31)), Some(new_fe(4, 31)));
+        assert!(matches!(result, Err(FieldElementError::InvalidField(_))));
+    }
+
+    #[test]
+    fn scalar_mul_over_f223() {
+        let (a, b) = (new_fe(0, 223), new_fe(7, 223));
+        let point = |x, y| Point::new(a.clone(), b.clone(), Some(x), Some(y)).unwrap();
+        let infinity = Point::new(a.clone(), b.clone(), None, None).unwrap();
+
+        let p = point(new_fe(47, 223), new_fe(71, 223));
+        assert_eq!((Scalar::from(0u8) * &p).unwrap(), infinity);
+        assert_eq!((Scalar::from(0u8) * &infinity).unwrap(), infinity);
+        assert_eq!(
+            (Scalar::from(2u8) * &p).unwrap(),
+            point(new_fe(36, 223), new_fe(111, 223))
+        );
+        // (15, 86) has order 7
+        let g = point(new_fe(15, 223), new_fe(86, 223));
+        assert_eq!((Scalar::from(7u8) * &g).unwrap(), infinity);
+        assert_eq!((Scalar::from(8u8) * &g).unwrap(), g);
+    }
+
+    #[test]
+    fn scalar_mul_rejects_mixed_fields() {
+        // bypasses Point::new, which would reject these coordinates
+        let p = Point {
+            a: new_fe(0, 223),
+            b: new_fe(7, 223),
+            x: Some(new_fe(3, 31)),
+            y: Some(new_fe(4, 31)),
+        };
+        for k in [0u8, 1, 5] {
+            assert!(matches!(
+                Scalar::from(k) * &p,
+                Err(FieldElementError::InvalidField(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn add_points_on_different_curves() {
+        let prime = 223;
+        // (1, 3) lies on y^2 = x^3 + 8 rather than y^2 = x^3 + 7
+        let p1 = Point::new(
+            new_fe(0, prime),
+            new_fe(7, prime),
+            Some(new_fe(47, prime)),
+            Some(new_fe(71, prime)),
+        )
+        .unwrap();
+        let p2 = Point::new(
+            new_fe(0, prime),
+            new_fe(8, prime),
+            Some(new_fe(1, prime)),
+            Some(new_fe(3, prime)),
+        )
+        .unwrap();
+
+        assert!(matches!(
+            &p1 + &p2,
+            Err(FieldElementError::DifferentCurves(_))
+        ));
+        assert!(matches!(
+            p1 + p2,
+            Err(FieldElementError::DifferentCurves(_))
+        ));
+    }
+
+    #[test]
+    fn order_of_small_points() {
+        let prime = 223;
+        let point = |x, y| {
+            Point::new(
+                new_fe(0, prime),
+                new_fe(7, prime),
+                Some(new_fe(x, prime)),
+                Some(new_fe(y, prime)),
+            )
+            .unwrap()
+        };
+
+        assert_eq!(point(15, 86).order(100), Some(7));
+        assert_eq!(point(47, 71).order(100), Some(21));
+        assert_eq!(point(47, 71).order(20), None);
+
+        let infinity = Point::new(new_fe(0, prime), new_fe(7, prime), None, None).unwrap();
+        assert_eq!(infinity.order(1), Some(1));
+    }
+
+    #[test]
+    fn all_points_on_small_curves() {
+        // y^2 = x^3 + 7 over F_223 has 252 points, and every point's order divides that
+        let points: Vec<_> = Point::all_points(new_fe(0, 223), new_fe(7, 223)).collect();
+        assert_eq!(points.len(), 252);
+        assert!(points[0].x.is_none());
+        for point in &points {
+            let on_curve = Point::new(
+                new_fe(0, 223),
+                new_fe(7, 223),
+                point.x.clone(),
+                point.y.clone(),
             );
+            assert_eq!(on_curve.unwrap(), *point);
+            assert_eq!(252 % point.order(252).unwrap(), 0);
+        }
+
+        // 17 = 1 (mod 8), so Tonelli-Shanks needs more than one round
+        assert_eq!(Point::all_points(new_fe(0, 17), new_fe(7, 17)).count(), 18);
+    }
+
+    #[test]
+    fn y_from_x() {
+        let (a, b) = (new_fe(0, 223), new_fe(7, 223));
+        let (y1, y2) = Point::y_from_x(&a, &b, &new_fe(47, 223)).unwrap();
+        assert_ne!(y1, y2);
+        assert!([y1.clone(), y2.clone()].contains(&new_fe(71, 223)));
+        for y in [y1, y2] {
+            assert!(Point::new(a.clone(), b.clone(), Some(new_fe(47, 223)), Some(y)).is_ok());
+        }
+
+        // 4^3 + 7 = 71 is not a square mod 223
+        assert!(matches!(
+            Point::y_from_x(&a, &b, &new_fe(4, 223)),
+            Err(FieldElementError::NotQuadraticResidue(_))
+        ));
+        assert!(matches!(
+            Point::y_from_x(&a, &b, &new_fe(5, 31)),
+            Err(FieldElementError::InvalidField(_))
+        ));
+    }
+
+    #[test]
+    fn sqrt_mod_small_primes() {
+        for prime in [3u32, 7, 13, 17, 41, 223] {
+            let p = BigInt::from(prime);
+            for w in 0..prime {
+                let w = BigInt::from(w);
+                let is_square = (0..prime).any(|y| BigInt::from(y * y) % &p == w);
+                match sqrt_mod(&w, &p) {
+                    Some(r) => assert_eq!(&r * &r % &p, w),
+                    None => assert!(!is_square),
+                }
+            }
         }
     }
 
+    #[test]
+    fn add_assign_accumulates() {
+        let prime = 223;
+        let g = Point::new(
+            new_fe(0, prime),
+            new_fe(7, prime),
+            Some(new_fe(47, prime)),
+            Some(new_fe(71, prime)),
+        )
+        .unwrap();
+
+        let mut acc = Point::new(new_fe(0, prime), new_fe(7, prime), None, None).unwrap();
+        for k in 1u8..=3 {
+            acc += &(Scalar::from(k) * &g).unwrap();
+        }
+        assert_eq!(acc, (Scalar::from(6u8) * &g).unwrap());
+
+        let other_curve = Point::new(new_fe(0, prime), new_fe(8, prime), None, None).unwrap();
+        assert!(matches!(
+            acc.try_add_assign(&other_curve),
+            Err(FieldElementError::DifferentCurves(_))
+        ));
+    }
+
     #[test]
     fn add_test() {
         // y^2 = x^3 - 7 over F-223
         let prime = 223;
-        let a = new_fe(0, prime.clone());
-        let b = new_fe(7, prime.clone());
+        let a = new_fe(0, prime);
+        let b = new_fe(7, prime);
 
         let additions = [
             //(x1, y2, x2, y2, x3, y3)
@@ -322,14 +675,14 @@ mod tests {
         ];
 
         for item in additions {
-            let x1 = new_fe(item.0, prime.clone());
-            let y1 = new_fe(item.1, prime.clone());
+            let x1 = new_fe(item.0, prime);
+            let y1 = new_fe(item.1, prime);
 
-            let x2 = new_fe(item.2, prime.clone());
-            let y2 = new_fe(item.3, prime.clone());
+            let x2 = new_fe(item.2, prime);
+            let y2 = new_fe(item.3, prime);
 
-            let x3 = new_fe(item.4, prime.clone());
-            let y3 = new_fe(item.5, prime.clone());
+            let x3 = new_fe(item.4, prime);
+            let y3 = new_fe(item.5, prime);
 
             assert_eq!(
                 (Point::new(a.clone(), b.clone(), Some(x1.clone()), Some(y1.clone())).unwrap()
@@ -343,15 +696,15 @@ mod tests {
         let p1 = Point::new(
             a.clone(),
             b.clone(),
-            Some(new_fe(192, prime.clone())),
-            Some(new_fe(105, prime.clone())),
+            Some(new_fe(192, prime)),
+            Some(new_fe(105, prime)),
         )
         .unwrap();
         let p2 = Point::new(
             a.clone(),
             b.clone(),
-            Some(new_fe(17, prime.clone())),
-            Some(new_fe(56, prime.clone())),
+            Some(new_fe(17, prime)),
+            Some(new_fe(56, prime)),
         )
         .unwrap();
         assert_eq!(
@@ -359,8 +712,8 @@ mod tests {
             Point::new(
                 a.clone(),
                 b.clone(),
-                Some(new_fe(170, prime.clone())),
-                Some(new_fe(142, prime.clone())),
+                Some(new_fe(170, prime)),
+                Some(new_fe(142, prime)),
             )
             .unwrap()
         );
@@ -369,10 +722,10 @@ mod tests {
     #[test]
     fn add_same_point() {
         let prime = 223;
-        let a = new_fe(0, prime.clone());
-        let b = new_fe(7, prime.clone());
-        let x = new_fe(47, prime.clone());
-        let y = new_fe(71, prime.clone());
+        let a = new_fe(0, prime);
+        let b = new_fe(7, prime);
+        let x = new_fe(47, prime);
+        let y = new_fe(71, prime);
         let p = Point::new(a.clone(), b.clone(), Some(x), Some(y)).unwrap();
 
         let result = (p.clone() + p.clone()).unwrap();
@@ -382,30 +735,86 @@ mod tests {
             Point::new(
                 a.clone(),
                 b.clone(),
-                Some(new_fe(36, prime.clone())),
-                Some(new_fe(111, prime.clone())),
+                Some(new_fe(36, prime)),
+                Some(new_fe(111, prime)),
             )
             .unwrap()
         )
     }
 
-    // #[test]
-    // fn scalar_multiplication_point() {
-    //     let prime = 223;
-    //     let a = new_fe(0, prime.clone());
-    //     let b = new_fe(7, prime.clone());
-    //     let x = new_fe(47, prime.clone());
-    //     let y = new_fe(71, prime.clone());
-    //     let p = Point::new(a.clone(), b.clone(), Some(x), Some(y)).unwrap();
-    //
-    //     assert_eq!(
-    //         Scalar::new(10) * p,
-    //         Point::new(
-    //             a,
-    //             b,
-    //             Some(new_fe(154, prime.clone())),
-    //             Some(new_fe(150, prime.clone())),
-    //         )
-    //     )
-    // }
+    #[test]
+    fn add_point_at_infinity_is_identity() {
+        let prime = 223;
+        let a = new_fe(0, prime);
+        let b = new_fe(7, prime);
+        let p = Point::new(
+            a.clone(),
+            b.clone(),
+            Some(new_fe(47, prime)),
+            Some(new_fe(71, prime)),
+        )
+        .unwrap();
+        let inf = Point::new(a, b, None, None).unwrap();
+
+        assert_eq!((&p + &inf).unwrap(), p);
+        assert_eq!((&inf + &p).unwrap(), p);
+        assert_eq!((p.clone() + inf.clone()).unwrap(), p);
+        assert_eq!((inf.clone() + inf.clone()).unwrap(), inf);
+    }
+
+    #[test]
+    fn add_inverse_is_infinity() {
+        let prime = 223;
+        let a = new_fe(0, prime);
+        let b = new_fe(7, prime);
+        // -(47, 71) = (47, 223 - 71)
+        let p = Point::new(
+            a.clone(),
+            b.clone(),
+            Some(new_fe(47, prime)),
+            Some(new_fe(71, prime)),
+        )
+        .unwrap();
+        let neg_p = Point::new(
+            a.clone(),
+            b.clone(),
+            Some(new_fe(47, prime)),
+            Some(new_fe(152, prime)),
+        )
+        .unwrap();
+        let inf = Point::new(a, b, None, None).unwrap();
+
+        assert_eq!((&p + &neg_p).unwrap(), inf);
+        assert_eq!((neg_p.clone() + p.clone()).unwrap(), inf);
+    }
+
+    #[test]
+    fn add_same_point_by_ref_matches_owned() {
+        let prime = 223;
+        let a = new_fe(0, prime);
+        let b = new_fe(7, prime);
+        let p = Point::new(a, b, Some(new_fe(47, prime)), Some(new_fe(71, prime))).unwrap();
+
+        assert_eq!((&p + &p).unwrap(), (p.clone() + p.clone()).unwrap());
+    }
+
+    #[test]
+    fn scalar_multiplication_point() {
+        let prime = 223;
+        let a = new_fe(0, prime);
+        let b = new_fe(7, prime);
+        let p = Point::new(
+            a.clone(),
+            b.clone(),
+            Some(new_fe(47, prime)),
+            Some(new_fe(71, prime)),
+        )
+        .unwrap();
+        let expected =
+            Point::new(a, b, Some(new_fe(154, prime)), Some(new_fe(150, prime))).unwrap();
+
+        assert_eq!((Scalar::from(10u8) * &p).unwrap(), expected);
+        assert_eq!((p.clone() * Scalar::from(10u8)).unwrap(), expected);
+        assert_eq!((Scalar::from(1u8) * &p).unwrap(), p);
+    }
 }
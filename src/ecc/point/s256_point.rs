@@ -1,53 +1,365 @@
-use std::fmt::{Display, Formatter};
-use std::ops::{Add, BitAnd, Mul};
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, AddAssign, Mul};
 
 use num_bigint::BigInt;
-use num_traits::{Num, One, Zero};
+use num_traits::{Num, Zero};
+use once_cell::race::OnceBox;
 
 use crate::ecc::abstractions::FieldElementTrait;
+use crate::ecc::curve::{self, Secp256k1};
 use crate::ecc::error::FieldElementError;
 use crate::ecc::point::point::Point;
 use crate::ecc::s256_field::S256Field;
 use crate::ecc::scalar::Scalar;
+use crate::ecc::signature::Signature;
+use crate::encoding::base58::base58check_encode;
+use crate::encoding::bech32::encode_segwit;
+use crate::encoding::hex;
+use crate::hash::hash160;
 
-#[derive(Debug, Clone)]
+static ORDER: OnceBox<BigInt> = OnceBox::new();
+static GENERATOR: OnceBox<S256Point> = OnceBox::new();
+
+#[derive(Clone)]
 pub struct S256Point {
     point: Point<S256Field>,
 }
 
 impl S256Point {
-    pub fn new(x: Option<S256Field>, y: Option<S256Field>) -> Self {
-        let a = S256Field::get_a();
-        let b = S256Field::get_b();
+    pub fn new(x: Option<S256Field>, y: Option<S256Field>) -> Result<Self, FieldElementError> {
+        let a = S256Field::get_a()?;
+        let b = S256Field::get_b()?;
 
-        Self {
-            point: Point::new(a, b, x, y).unwrap(),
-        }
+        Ok(Self {
+            point: Point::new(a, b, x, y)?,
+        })
     }
 
-    fn get_generator_point() -> S256Point {
-        let gx = S256Field::new(
-            BigInt::from_str_radix(
-                "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
-                16,
-            )
-            .unwrap(),
-        );
+    /// x-coordinate, `None` for the point at infinity.
+    pub fn x(&self) -> Option<&S256Field> {
+        self.point.x.as_ref()
+    }
 
-        let gy = S256Field::new(
-            BigInt::from_str_radix(
-                "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
-                16,
+    /// y-coordinate, `None` for the point at infinity.
+    pub fn y(&self) -> Option<&S256Field> {
+        self.point.y.as_ref()
+    }
+
+    /// secp256k1 generator point `G`.
+    pub fn generator() -> S256Point {
+        GENERATOR
+            .get_or_init(|| {
+                let gx = S256Field::new(
+                    BigInt::from_str_radix(
+                        "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+                        16,
+                    )
+                    .unwrap(),
+                )
+                .unwrap();
+
+                let gy = S256Field::new(
+                    BigInt::from_str_radix(
+                        "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+                        16,
+                    )
+                    .unwrap(),
+                )
+                .unwrap();
+
+                Box::new(
+                    S256Point::new(Some(gx), Some(gy))
+                        .expect("Gx/Gy constants do not satisfy y^2 = x^3 + 7"),
+                )
+            })
+            .clone()
+    }
+
+    /// Order `N` of the group generated by `G`.
+    pub fn order() -> &'static BigInt {
+        ORDER.get_or_init(|| {
+            Box::new(
+                BigInt::from_str_radix(
+                    "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+                    16,
+                )
+                .unwrap(),
             )
-            .unwrap(),
-        );
+        })
+    }
+
+    /// SEC uncompressed encoding: `0x04 || x || y`.
+    ///
+    /// Panics on the point at infinity, which has no SEC encoding.
+    pub fn to_sec_uncompressed(&self) -> [u8; 65] {
+        let (x, y) = self.coordinates();
+        let mut out = [0u8; 65];
+        out[0] = 0x04;
+        out[1..33].copy_from_slice(&x.to_bytes());
+        out[33..].copy_from_slice(&y.to_bytes());
+        out
+    }
+
+    /// SEC compressed encoding: `0x02`/`0x03` (parity of y) followed by x.
+    ///
+    /// Panics on the point at infinity, which has no SEC encoding.
+    pub fn to_sec_compressed(&self) -> [u8; 33] {
+        let (x, y) = self.coordinates();
+        let mut out = [0u8; 33];
+        out[0] = y.parity_byte();
+        out[1..].copy_from_slice(&x.to_bytes());
+        out
+    }
+
+    /// SEC encoding, compressed or uncompressed.
+    ///
+    /// Panics on the point at infinity, which has no SEC encoding.
+    pub fn serialize(&self, compressed: bool) -> Vec<u8> {
+        if compressed {
+            self.to_sec_compressed().to_vec()
+        } else {
+            self.to_sec_uncompressed().to_vec()
+        }
+    }
+
+    /// Parses a SEC encoded point, detecting the format from the prefix byte: `0x04` for
+    /// 65-byte uncompressed, `0x02`/`0x03` for 33-byte compressed. For the compressed form
+    /// y is recovered from `y^2 = x^3 + 7`. Either way a point not on the curve is rejected
+    /// with [`FieldElementError::NotOnCurve`].
+    pub fn parse(sec: &[u8]) -> Result<S256Point, FieldElementError> {
+        let expected_len = match sec.first() {
+            Some(0x04) => 65,
+            Some(0x02 | 0x03) => 33,
+            Some(prefix) => {
+                return Err(FieldElementError::InvalidEncoding(format!(
+                    "unknown SEC prefix 0x{:02x}",
+                    prefix
+                )))
+            }
+            None => {
+                return Err(FieldElementError::InvalidEncoding(
+                    "empty SEC encoding".to_string(),
+                ))
+            }
+        };
+        if sec.len() != expected_len {
+            return Err(FieldElementError::InvalidEncoding(format!(
+                "SEC prefix 0x{:02x} needs {} bytes, got {}",
+                sec[0],
+                expected_len,
+                sec.len()
+            )));
+        }
+
+        let x = S256Field::from_bytes(sec[1..33].try_into().unwrap())?;
+        if sec[0] == 0x04 {
+            let y = S256Field::from_bytes(sec[33..].try_into().unwrap())?;
+            return S256Point::new(Some(x), Some(y));
+        }
+
+        // without a root of x^3 + 7 no point on the curve has this x
+        S256Point::from_x_and_parity(&x, sec[0] == 0x02).map_err(|err| match err {
+            FieldElementError::NotQuadraticResidue(_) => FieldElementError::NotOnCurve(format!(
+                "no point on the curve has x = {:x}",
+                x.get_num()
+            )),
+            err => err,
+        })
+    }
+
+    /// The point with this `x` whose y is even (`even`) or odd: solves `y^2 = x^3 + 7` and
+    /// picks the root by parity. Errors with [`FieldElementError::NotQuadraticResidue`] when
+    /// `x^3 + 7` has no square root.
+    pub fn from_x_and_parity(x: &S256Field, even: bool) -> Result<S256Point, FieldElementError> {
+        let y = (x.pow_mod_ref(&BigInt::from(3u8))? + S256Field::get_b()?)?.sqrt()?;
+        let y = if y.is_even() == even { y } else { y.neg()? };
+        S256Point::new(Some(x.clone()), Some(y))
+    }
+
+    /// BIP340 x-only encoding, the 32-byte x-coordinate with y implicitly even.
+    ///
+    /// Panics on the point at infinity.
+    pub fn to_x_only(&self) -> [u8; 32] {
+        self.coordinates().0.to_bytes()
+    }
+
+    /// Recovers the point with even y from an x-only encoding. Errors when
+    /// `x >= p` or `x^3 + 7` has no square root.
+    pub fn lift_x(x: &[u8; 32]) -> Result<S256Point, FieldElementError> {
+        S256Point::from_x_and_parity(&S256Field::from_bytes(x)?, true)
+    }
+
+    /// secp256k1 has cofactor 1, so every point is already in the group generated by `G`
+    /// and this is the identity. Kept for symmetry with [`crate::ecc::curve::clear_cofactor`].
+    pub fn clear_cofactor(&self) -> S256Point {
+        self.clone()
+    }
+
+    /// Whether y is even, the implicit choice for BIP340 x-only keys and nonces. `false` for
+    /// the point at infinity.
+    pub fn has_even_y(&self) -> bool {
+        self.y().is_some_and(|y| y.is_even())
+    }
+
+    /// Orders points by their 33-byte compressed SEC encoding, as BIP67 sorted multisig and
+    /// MuSig key aggregation do. The point at infinity sorts before every other point.
+    pub fn cmp_lexicographic(&self, other: &S256Point) -> Ordering {
+        match (self.point.x.is_none(), other.point.x.is_none()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => self.to_sec_compressed().cmp(&other.to_sec_compressed()),
+        }
+    }
+
+    /// `self = self + other`, for accumulating sums without rebinding.
+    pub fn try_add_assign(&mut self, other: &S256Point) -> Result<(), FieldElementError> {
+        self.point.try_add_assign(&other.point)
+    }
+
+    /// `self + tweak*G`, as used to derive a Taproot output key from an internal key.
+    /// Errors when `tweak` is not in `[0, N)` or the result is the point at infinity.
+    pub fn tweak_add(&self, tweak: &Scalar) -> Result<S256Point, FieldElementError> {
+        check_tweak(tweak)?;
+        let tweaked = (self + &(&S256Point::generator() * tweak)?)?;
+        if tweaked.point.x.is_none() {
+            return Err(FieldElementError::PointAtInfinity(
+                "tweaked point is infinity".to_string(),
+            ));
+        }
+        Ok(tweaked)
+    }
+
+    /// Key identifier used by BIP32 parent fingerprints and PSBT key origins: the first 4
+    /// bytes of `hash160` of the compressed SEC encoding.
+    ///
+    /// Panics on the point at infinity.
+    pub fn fingerprint(&self) -> [u8; 4] {
+        let mut out = [0u8; 4];
+        out.copy_from_slice(&hash160(&self.to_sec_compressed())[..4]);
+        out
+    }
+
+    /// P2PKH address: base58check of a version byte (`0x00` mainnet, `0x6f` testnet)
+    /// followed by the hash160 of the SEC encoding.
+    ///
+    /// Panics on the point at infinity, which has no SEC encoding.
+    pub fn address(&self, compressed: bool, testnet: bool) -> String {
+        let h160 = if compressed {
+            hash160(&self.to_sec_compressed())
+        } else {
+            hash160(&self.to_sec_uncompressed())
+        };
+
+        let mut payload = vec![if testnet { 0x6f } else { 0x00 }];
+        payload.extend_from_slice(&h160);
+        base58check_encode(&payload)
+    }
+
+    /// P2WPKH address: a version 0 witness program holding the hash160 of the compressed
+    /// SEC encoding, bech32-encoded with hrp `bc` (mainnet) or `tb` (testnet).
+    ///
+    /// Panics on the point at infinity, which has no SEC encoding.
+    pub fn segwit_address(&self, testnet: bool) -> String {
+        let hrp = if testnet { "tb" } else { "bc" };
+        encode_segwit(hrp, 0, &hash160(&self.to_sec_compressed()))
+            .expect("a 20-byte v0 program is always encodable")
+    }
+
+    /// P2TR address: a version 1 witness program holding this point as the x-only output
+    /// key, bech32m-encoded with hrp `bc` (mainnet) or `tb` (testnet). No taproot tweak is
+    /// applied.
+    ///
+    /// Panics on the point at infinity, which has no x-only encoding.
+    pub fn taproot_address(&self, testnet: bool) -> String {
+        let hrp = if testnet { "tb" } else { "bc" };
+        encode_segwit(hrp, 1, &self.to_x_only()).expect("a 32-byte v1 program is always encodable")
+    }
+
+    fn coordinates(&self) -> (&S256Field, &S256Field) {
+        match (&self.point.x, &self.point.y) {
+            (Some(x), Some(y)) => (x, y),
+            _ => panic!("the point at infinity has no SEC encoding"),
+        }
+    }
+
+    /// `scalar * self` by a Montgomery ladder: every one of the 256 steps does one addition
+    /// and one doubling whatever the bit, so the work done doesn't depend on the scalar's
+    /// Hamming weight. Gives the same result as `scalar * &point`, reduced mod `N`.
+    pub fn mul_ct(&self, scalar: &Scalar) -> Result<S256Point, FieldElementError> {
+        let k = Scalar::reduce_mod_n(scalar.n.clone()).n;
+
+        // R1 - R0 == self throughout, and R0 ends up as k * self
+        let mut r0 = S256Point::new(None, None)?;
+        let mut r1 = self.clone();
+        for i in (0..256).rev() {
+            // NOTE: the swap and the BigInt arithmetic underneath still branch, and nothing
+            // stops the compiler from adding more; this is not constant time without a
+            // fixed-width field implementation and a masked swap.
+            let bit = k.bit(i);
+            conditional_swap(&mut r0, &mut r1, bit);
+            r1 = (&r0 + &r1)?;
+            r0 = (&r0 + &r0)?;
+            conditional_swap(&mut r0, &mut r1, bit);
+        }
+        Ok(r0)
+    }
+
+    /// Whether this point is usable as a public key: not the point at infinity, on the curve
+    /// (which [`S256Point::new`] already enforces) and of order `N`, i.e. `N * self` is the
+    /// point at infinity. secp256k1 has cofactor 1, so the order check is what guards against
+    /// small-subgroup points.
+    pub fn is_valid_public_key(&self) -> bool {
+        if self.point.x.is_none() {
+            return false;
+        }
+
+        // through the generic curve arithmetic, as `Scalar * &S256Point` reduces N to 0
+        match Scalar::new(S256Point::order().clone()) * &self.point {
+            Ok(res) => res.x.is_none(),
+            Err(_) => false,
+        }
+    }
+
+    /// Verifies an ECDSA signature of message hash `z` against this public key:
+    /// `u = z/s`, `v = r/s` (mod N) and the signature is valid when `(u*G + v*P).x == r
+    /// (mod N)`. `r` and `s` outside `[1, N)` never verify.
+    pub fn verify(&self, z: &BigInt, sig: &Signature) -> Result<bool, FieldElementError> {
+        curve::verify::<Secp256k1>(&self.point, z, sig)
+    }
+}
+
+/// Sorts public keys in place by [`S256Point::cmp_lexicographic`]. The sort is stable.
+pub fn sort_pubkeys(keys: &mut [S256Point]) {
+    keys.sort_by(S256Point::cmp_lexicographic);
+}
+
+/// Tweaks must lie in `[0, N)`; BIP341 rejects anything larger rather than reducing it.
+pub(crate) fn check_tweak(tweak: &Scalar) -> Result<(), FieldElementError> {
+    if tweak.n < BigInt::zero() || tweak.n >= *S256Point::order() {
+        return Err(FieldElementError::ScalarOutOfRange(format!(
+            "Tweak {} not in range 0 to {}",
+            tweak.n,
+            S256Point::order() - 1
+        )));
+    }
+    Ok(())
+}
 
-        S256Point::new(Some(gx), Some(gy))
+fn conditional_swap(a: &mut S256Point, b: &mut S256Point, swap: bool) {
+    if swap {
+        core::mem::swap(a, b);
     }
 }
 
 impl Display for S256Point {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         if self.point.x.is_none() {
             return write!(f, "S256Point(infinity)");
         }
@@ -60,7 +372,7 @@ impl Display for S256Point {
             .map(|v| format!("S256Field_{}({})", v.get_num(), v.get_prime()))
             .unwrap_or_else(|| format!("S256Field_None({})", prime));
 
-        return write!(
+        write!(
             f,
             "S256Point({}, {})_{}_{} S256Field({})",
             self.point.x.as_ref().unwrap(),
@@ -68,7 +380,26 @@ impl Display for S256Point {
             &self.point.a,
             &self.point.b,
             prime,
-        );
+        )
+    }
+}
+
+/// Coordinates as zero-padded hex rather than the decimal `BigInt`s a derived `Debug` shows.
+impl core::fmt::Debug for S256Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut debug = f.debug_struct("S256Point");
+        if let (Some(x), Some(y)) = (self.x(), self.y()) {
+            debug
+                .field("x", &format_args!("{}", hex::encode(&x.to_bytes())))
+                .field("y", &format_args!("{}", hex::encode(&y.to_bytes())));
+        }
+        debug.field("infinity", &self.point.x.is_none()).finish()
+    }
+}
+
+impl From<S256Point> for Point<S256Field> {
+    fn from(point: S256Point) -> Self {
+        point.point
     }
 }
 
@@ -78,6 +409,14 @@ impl PartialEq for S256Point {
     }
 }
 
+impl Eq for S256Point {}
+
+impl Hash for S256Point {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.point.hash(state);
+    }
+}
+
 impl Add for S256Point {
     type Output = Result<S256Point, FieldElementError>;
 
@@ -87,7 +426,7 @@ impl Add for S256Point {
     }
 }
 
-impl<'a, 'b> Add<&'b S256Point> for &'a S256Point {
+impl<'b> Add<&'b S256Point> for &S256Point {
     type Output = Result<S256Point, FieldElementError>;
 
     fn add(self, rhs: &'b S256Point) -> Self::Output {
@@ -96,22 +435,31 @@ impl<'a, 'b> Add<&'b S256Point> for &'a S256Point {
     }
 }
 
+/// Panics if the addition fails, which can't happen for two valid secp256k1 points; use
+/// [`S256Point::try_add_assign`] to get the error instead.
+impl<'b> AddAssign<&'b S256Point> for S256Point {
+    fn add_assign(&mut self, rhs: &'b S256Point) {
+        self.try_add_assign(rhs)
+            .expect("secp256k1 point addition failed");
+    }
+}
+
 impl Mul<&S256Point> for Scalar {
     type Output = Result<S256Point, FieldElementError>;
 
     fn mul(self, rhs: &S256Point) -> Self::Output {
-        let mut coef = self.n;
-        let one = BigInt::one();
+        // k and k mod N give the same point; reducing also folds negative k into [0, N)
+        let mut coef = Scalar::reduce_mod_n(self.n).n;
         let zero = BigInt::zero();
 
         let mut current = rhs.clone();
-        let mut result = S256Point::new(None, None);
+        let mut result = S256Point::new(None, None)?;
 
         while coef > zero {
-            if &coef.clone().bitand(&one) == &one {
-                result = (&result + &current).unwrap();
+            if coef.bit(0) {
+                result = (&result + &current)?;
             }
-            current = (&current + &current).unwrap();
+            current = (&current + &current)?;
             coef >>= 1;
         }
 
@@ -119,13 +467,51 @@ impl Mul<&S256Point> for Scalar {
     }
 }
 
+impl Mul<&Scalar> for &S256Point {
+    type Output = Result<S256Point, FieldElementError>;
+
+    fn mul(self, rhs: &Scalar) -> Self::Output {
+        rhs.clone() * self
+    }
+}
+
+impl Mul<Scalar> for S256Point {
+    type Output = Result<S256Point, FieldElementError>;
+
+    fn mul(self, rhs: Scalar) -> Self::Output {
+        rhs * &self
+    }
+}
+
+impl Mul<u32> for &S256Point {
+    type Output = Result<S256Point, FieldElementError>;
+
+    fn mul(self, rhs: u32) -> Self::Output {
+        Scalar::from(rhs) * self
+    }
+}
+
+impl Mul<u64> for &S256Point {
+    type Output = Result<S256Point, FieldElementError>;
+
+    fn mul(self, rhs: u64) -> Self::Output {
+        Scalar::from(rhs) * self
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::ecc::point::s256_point::S256Point;
+    use core::cmp::Ordering;
+
+    use crate::ecc::abstractions::FieldElementTrait;
+    use crate::ecc::error::FieldElementError;
+    use crate::ecc::point::s256_point::{sort_pubkeys, S256Point};
     use crate::ecc::s256_field::S256Field;
     use crate::ecc::scalar::Scalar;
+    use crate::ecc::signature::{recover_public_key, RecoverableSignature, Signature};
+    use crate::encoding::hex;
     use num_bigint::BigInt;
-    use num_traits::{Num, One, Pow};
+    use num_traits::{Num, One, Pow, Zero};
 
     fn from_hex(input: &str) -> BigInt {
         BigInt::from_str_radix(input, 16).unwrap()
@@ -159,20 +545,603 @@ mod test {
 
         for point in points {
             let private_key = point.0;
-            let x = S256Field::new(point.1);
-            let y = S256Field::new(point.2);
-            let pt = S256Point::new(Some(x), Some(y));
+            let x = S256Field::new(point.1).unwrap();
+            let y = S256Field::new(point.2).unwrap();
+            let pt = S256Point::new(Some(x), Some(y)).unwrap();
 
             assert_eq!(
-                (private_key.clone() * &S256Point::get_generator_point()).unwrap(),
+                (private_key.clone() * &S256Point::generator()).unwrap(),
                 pt.clone()
             );
 
             let invalid_key = Scalar::new(private_key.n - BigInt::one());
-            assert_ne!(
-                (invalid_key * &S256Point::get_generator_point()).unwrap(),
-                pt
-            )
+            assert_ne!((invalid_key * &S256Point::generator()).unwrap(), pt)
+        }
+    }
+
+    #[test]
+    fn scalar_multiplication_is_commutative() {
+        let g = S256Point::generator();
+        let p = (Scalar::from(1485u32) * &g).unwrap();
+        let s = Scalar::new(from_hex("deadbeef12345"));
+
+        let left = (s.clone() * &p).unwrap();
+        assert_eq!((&p * &s).unwrap(), left);
+        assert_eq!((p * s).unwrap(), left);
+    }
+
+    #[test]
+    fn hash_set_of_points() {
+        use std::collections::HashSet;
+
+        let g = S256Point::generator();
+        let points: HashSet<S256Point> = [1u32, 2, 3, 2, 1]
+            .into_iter()
+            .map(|k| (Scalar::from(k) * &g).unwrap())
+            .collect();
+
+        assert_eq!(points.len(), 3);
+        assert!(points.contains(&(Scalar::from(2u32) * &g).unwrap()));
+        assert!(points.contains(&g));
+        assert!(!points.contains(&(Scalar::from(4u32) * &g).unwrap()));
+    }
+
+    #[test]
+    fn mul_ct_matches_double_and_add() {
+        let g = S256Point::generator();
+        let p = (Scalar::from(1485u32) * &g).unwrap();
+        let scalars = [
+            Scalar::from(0u8),
+            Scalar::from(1u8),
+            Scalar::from(2u8),
+            Scalar::from(0xdeadbeefu32),
+            Scalar::new(BigInt::from(2).pow(128u32)),
+            Scalar::new(from_hex(
+                "8f68b9d2f63b5f339239c1ad981f162ee88c5678723ea3351b7b444c9ec4c0da",
+            )),
+            Scalar::new(S256Point::order() - BigInt::from(2u8)),
+        ];
+
+        for k in scalars {
+            assert_eq!(g.mul_ct(&k).unwrap(), (k.clone() * &g).unwrap());
+            assert_eq!(p.mul_ct(&k).unwrap(), (k.clone() * &p).unwrap());
+        }
+
+        // reduced mod N first
+        let seven = Scalar::new(S256Point::order() + BigInt::from(7u8));
+        assert_eq!(g.mul_ct(&seven).unwrap(), (Scalar::from(7u8) * &g).unwrap());
+    }
+
+    #[test]
+    fn curve_constants() {
+        assert_eq!(
+            *S256Point::order(),
+            from_hex("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141")
+        );
+        assert!(core::ptr::eq(S256Point::order(), S256Point::order()));
+
+        let g = S256Point::generator();
+        assert_eq!(
+            *g.point.x.as_ref().unwrap().get_num(),
+            from_hex("79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798")
+        );
+        assert_eq!(
+            *g.point.y.as_ref().unwrap().get_num(),
+            from_hex("483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8")
+        );
+        assert_eq!(g, S256Point::generator());
+    }
+
+    #[test]
+    fn generator_is_on_curve_with_order_n() {
+        let g = S256Point::generator();
+        let (x, y) = (g.x().unwrap(), g.y().unwrap());
+        let rhs =
+            (x.pow_mod_ref(&BigInt::from(3u8)).unwrap() + S256Field::get_b().unwrap()).unwrap();
+        assert_eq!(y.square().unwrap(), rhs, "G is not on y^2 = x^3 + 7");
+
+        let n_g = (Scalar::new(S256Point::order().clone()) * &g).unwrap();
+        assert!(n_g.x().is_none(), "N * G is not the point at infinity");
+        assert!(g.is_valid_public_key());
+    }
+
+    #[test]
+    fn from_x_and_parity() {
+        for k in [1u32, 6, 0xdead_beef] {
+            let p = (Scalar::from(k) * &S256Point::generator()).unwrap();
+            let x = p.x().unwrap();
+            let even = S256Point::from_x_and_parity(x, true).unwrap();
+            let odd = S256Point::from_x_and_parity(x, false).unwrap();
+
+            assert!(even.has_even_y());
+            assert!(!odd.has_even_y());
+            assert_eq!(if p.has_even_y() { &even } else { &odd }, &p);
+            assert_eq!(even.to_sec_compressed()[0], 0x02);
+            assert_eq!(odd.to_sec_compressed()[0], 0x03);
+        }
+
+        // x = 5 gives x^3 + 7 = 132, which has no square root mod p
+        assert!(matches!(
+            S256Point::from_x_and_parity(&S256Field::new(BigInt::from(5u8)).unwrap(), true),
+            Err(FieldElementError::NotQuadraticResidue(_))
+        ));
+    }
+
+    #[test]
+    fn parities_are_negations() {
+        let x = S256Point::generator().x().unwrap().clone();
+        let even = S256Point::from_x_and_parity(&x, true).unwrap();
+        let odd = S256Point::from_x_and_parity(&x, false).unwrap();
+
+        assert_eq!(even.x(), odd.x());
+        let (even_y, odd_y) = (even.y().unwrap().get_num(), odd.y().unwrap().get_num());
+        assert_eq!(even_y + odd_y, *S256Field::prime());
+        assert!((&even + &odd).unwrap().x().is_none());
+    }
+
+    #[test]
+    fn fingerprint() {
+        // hash160(sec(G)) = 751e76e8199196d454941c45d1b3a323f1433bd6
+        assert_eq!(
+            S256Point::generator().fingerprint(),
+            [0x75, 0x1e, 0x76, 0xe8]
+        );
+    }
+
+    #[test]
+    fn sec_uncompressed() {
+        let sec = S256Point::generator().to_sec_uncompressed();
+        assert_eq!(
+            hex::encode(&sec),
+            "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798\
+             483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"
+        );
+
+        // (secret, sec)
+        let vectors = [
+            (
+                Scalar::from(5000u32),
+                "04ffe558e388852f0120e46af2d1b370f85854a8eb0841811ece0e3e03d282d57c315dc72890a4\
+                 f10a1481c031b03b351b0dc79901ca18a00cf009dbdb157a1d10",
+            ),
+            (
+                Scalar::new(from_hex("deadbeef12345")),
+                "04d90cd625ee87dd38656dd95cf79f65f60f7273b67d3096e68bd81e4f5342691f842efa762fd5\
+                 9961d0e99803c61edba8b3e3f7dc3a341836f97733aebf987121",
+            ),
+        ];
+        for (secret, sec) in vectors {
+            let point = (secret * &S256Point::generator()).unwrap();
+            assert_eq!(hex::encode(&point.to_sec_uncompressed()), sec);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "point at infinity has no SEC encoding")]
+    fn sec_uncompressed_infinity() {
+        S256Point::new(None, None).unwrap().to_sec_uncompressed();
+    }
+
+    #[test]
+    fn sec_compressed() {
+        // (secret, sec)
+        let vectors = [
+            (
+                Scalar::from(5001u32),
+                "0357a4f368868a8a6d572991e484e664810ff14c05c0fa023275251151fe0e53d1",
+            ),
+            (
+                Scalar::new(BigInt::from(2019u32).pow(5u32)),
+                "02933ec2d2b111b92737ec12f1c5d20f3233a0ad21cd8b36d0bca7a0cfa5cb8701",
+            ),
+            (
+                Scalar::new(from_hex("deadbeef54321")),
+                "0296be5b1292f6c856b3c5654e886fc13511462059089cdf9c479623bfcbe77690",
+            ),
+        ];
+        for (secret, sec) in vectors {
+            let point = (secret * &S256Point::generator()).unwrap();
+            assert_eq!(hex::encode(&point.to_sec_compressed()), sec);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "point at infinity has no SEC encoding")]
+    fn sec_compressed_infinity() {
+        S256Point::new(None, None).unwrap().to_sec_compressed();
+    }
+
+    #[test]
+    fn parse_sec() {
+        let secrets = [
+            Scalar::from(5000u32),
+            Scalar::from(5001u32),
+            Scalar::new(BigInt::from(2019u32).pow(5u32)),
+            Scalar::new(from_hex("deadbeef54321")),
+        ];
+        for secret in secrets {
+            let point = (secret * &S256Point::generator()).unwrap();
+            assert_eq!(S256Point::parse(&point.to_sec_compressed()).unwrap(), point);
+            assert_eq!(
+                S256Point::parse(&point.to_sec_uncompressed()).unwrap(),
+                point
+            );
+        }
+    }
+
+    #[test]
+    fn serialize_round_trip() {
+        let secrets = [
+            Scalar::from(1u8),
+            Scalar::from(6u8),
+            Scalar::new(from_hex("deadbeef54321")),
+        ];
+        for secret in secrets {
+            let point = (secret * &S256Point::generator()).unwrap();
+
+            let compressed = point.serialize(true);
+            assert_eq!(compressed, point.to_sec_compressed().to_vec());
+            assert_eq!(S256Point::parse(&compressed).unwrap(), point);
+
+            let uncompressed = point.serialize(false);
+            assert_eq!(uncompressed, point.to_sec_uncompressed().to_vec());
+            assert_eq!(S256Point::parse(&uncompressed).unwrap(), point);
+        }
+    }
+
+    #[test]
+    fn parse_sec_rejects_invalid_input() {
+        let g = S256Point::generator();
+        let compressed = g.to_sec_compressed();
+        let uncompressed = g.to_sec_uncompressed();
+
+        assert!(S256Point::parse(&[]).is_err());
+        assert!(S256Point::parse(&compressed[..32]).is_err());
+        assert!(S256Point::parse(&uncompressed[..64]).is_err());
+
+        let mut bad_prefix = compressed;
+        bad_prefix[0] = 0x04;
+        assert!(S256Point::parse(&bad_prefix).is_err());
+
+        let mut unknown_prefix = compressed;
+        unknown_prefix[0] = 0x05;
+        assert!(matches!(
+            S256Point::parse(&unknown_prefix),
+            Err(FieldElementError::InvalidEncoding(_))
+        ));
+        // a compressed prefix on an uncompressed-length input
+        let mut mismatched = uncompressed;
+        mismatched[0] = 0x02;
+        assert!(matches!(
+            S256Point::parse(&mismatched),
+            Err(FieldElementError::InvalidEncoding(_))
+        ));
+
+        // y nudged off the curve
+        let mut off_curve = uncompressed;
+        off_curve[64] ^= 0x01;
+        assert!(matches!(
+            S256Point::parse(&off_curve),
+            Err(FieldElementError::NotOnCurve(_))
+        ));
+
+        // x = 5 gives 5^3 + 7 = 132, which is not a square mod p, so neither y exists
+        for prefix in [0x02, 0x03] {
+            let mut invalid_x = [0u8; 33];
+            invalid_x[0] = prefix;
+            invalid_x[32] = 5;
+            assert!(matches!(
+                S256Point::parse(&invalid_x),
+                Err(FieldElementError::NotOnCurve(_))
+            ));
+        }
+
+        // x = p is not a field element
+        let mut out_of_range = [0xffu8; 33];
+        out_of_range[0] = 0x02;
+        assert!(S256Point::parse(&out_of_range).is_err());
+    }
+
+    #[test]
+    fn address() {
+        // (secret, compressed, testnet, address)
+        let vectors = [
+            (
+                Scalar::from(5002u32),
+                false,
+                true,
+                "mmTPbXQFxboEtNRkwfh6K51jvdtHLxGeMA",
+            ),
+            (
+                Scalar::from(5002u32),
+                false,
+                false,
+                "16wSJUKH9aMz7Fx9E6iiV9oR4eHaMFaWCB",
+            ),
+            (
+                Scalar::new(BigInt::from(2020u32).pow(5u32)),
+                true,
+                true,
+                "mopVkxp8UhXqRYbCYJsbeE1h1fiF64jcoH",
+            ),
+            (
+                Scalar::new(from_hex("12345deadbeef")),
+                true,
+                false,
+                "1F1Pn2y6pDb68E5nYJJeba4TLg2U7B6KF1",
+            ),
+        ];
+
+        for (secret, compressed, testnet, address) in vectors {
+            let point = (secret * &S256Point::generator()).unwrap();
+            assert_eq!(point.address(compressed, testnet), address);
+        }
+    }
+
+    #[test]
+    fn segwit_address() {
+        let g = S256Point::generator();
+        assert_eq!(
+            g.segwit_address(false),
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"
+        );
+        assert_eq!(
+            g.segwit_address(true),
+            "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx"
+        );
+    }
+
+    #[test]
+    fn clear_cofactor_is_identity() {
+        let g = S256Point::generator();
+        assert_eq!(g.clear_cofactor(), g);
+    }
+
+    #[test]
+    fn has_even_y() {
+        let g = S256Point::generator();
+        assert!(g.has_even_y());
+        assert!(!S256Point::new(None, None).unwrap().has_even_y());
+
+        // 6G has an odd y; negating the scalar gives the even-y point with the same x
+        let six = Scalar::from(6u8);
+        let p = (six.clone() * &g).unwrap();
+        assert!(!p.has_even_y());
+
+        let even = (six.negate_if(!p.has_even_y()) * &g).unwrap();
+        assert!(even.has_even_y());
+        assert_eq!(even.x(), p.x());
+        assert_eq!(even, S256Point::lift_x(&p.to_x_only()).unwrap());
+    }
+
+    #[test]
+    fn taproot_address() {
+        let g = S256Point::generator();
+        assert_eq!(
+            g.taproot_address(false),
+            "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0"
+        );
+
+        // an odd-y point shares the address of its even-y negation
+        let p = (Scalar::from(6u8) * &g).unwrap();
+        assert!(!p.y().unwrap().is_even());
+        let neg = S256Point::lift_x(&p.to_x_only()).unwrap();
+        assert_ne!(p, neg);
+        assert_eq!(p.taproot_address(true), neg.taproot_address(true));
+        assert!(p.taproot_address(true).starts_with("tb1p"));
+    }
+
+    #[test]
+    fn x_only_round_trip() {
+        let secrets = [
+            Scalar::from(1u8),
+            Scalar::from(5001u32),
+            Scalar::new(from_hex("deadbeef54321")),
+        ];
+        for secret in secrets {
+            let point = (secret * &S256Point::generator()).unwrap();
+            let lifted = S256Point::lift_x(&point.to_x_only()).unwrap();
+
+            assert_eq!(lifted.to_x_only(), point.to_x_only());
+            assert!(lifted.y().unwrap().is_even());
+            if point.y().unwrap().is_even() {
+                assert_eq!(lifted, point);
+            } else {
+                // the odd-y point is the negation: y + y' = p
+                let sum = (lifted.y().unwrap().clone() + point.y().unwrap()).unwrap();
+                assert_eq!(*sum.get_num(), BigInt::zero());
+            }
+        }
+    }
+
+    #[test]
+    fn lift_x_rejects_invalid_x() {
+        assert!(S256Point::lift_x(&[0xff; 32]).is_err());
+
+        // x = 5 gives 5^3 + 7 = 132, which is not a square mod p
+        let mut x = [0u8; 32];
+        x[31] = 5;
+        assert!(matches!(
+            S256Point::lift_x(&x),
+            Err(FieldElementError::NotQuadraticResidue(_))
+        ));
+    }
+
+    #[test]
+    fn verify() {
+        let point = S256Point::new(
+            Some(
+                S256Field::new(from_hex(
+                    "887387e452b8eacc4acfde10d9aaf7f6d9a0f975aabb10d006e4da568744d06c",
+                ))
+                .unwrap(),
+            ),
+            Some(
+                S256Field::new(from_hex(
+                    "61de6d95231cd89026e286df3b6ae4a894a3378e393e93a0f45b666329a0ae34",
+                ))
+                .unwrap(),
+            ),
+        )
+        .unwrap();
+
+        // (z, r, s)
+        let vectors = [
+            (
+                "ec208baa0fc1c19f708a9ca96fdeff3ac3f230bb4a7ba4aede4942ad003c0f60",
+                "ac8d1c87e51d0d441be8b3dd5b05c8795b48875dffe00b7ffcfac23010d3a395",
+                "68342ceff8935ededd102dd876ffd6ba72d6a427a3edb13d26eb0781cb423c4",
+            ),
+            (
+                "7c076ff316692a3d7eb3c3bb0f8b1488cf72e1afcd929e29307032997a838a3d",
+                "eff69ef2b1bd93a66ed5219add4fb51e11a840f404876325a1e8ffe0529a2c",
+                "c7207fee197d27c618aea621406f6bf5ef6fca38681d82b2f06fddbdce6feab6",
+            ),
+        ];
+
+        for (z, r, s) in vectors {
+            let z = from_hex(z);
+            let sig = Signature::new_unchecked(from_hex(r), from_hex(s));
+            assert!(point.verify(&z, &sig).unwrap());
+
+            let tampered = z + BigInt::one();
+            assert!(!point.verify(&tampered, &sig).unwrap());
         }
     }
+
+    #[test]
+    fn verify_rejects_infinity() {
+        // with P = e*G, u*G + v*P = (z + r*e)/s * G, which is infinity when z = -r*e mod N
+        let e = BigInt::from(0xdead_beefu32);
+        let point = (Scalar::new(e.clone()) * &S256Point::generator()).unwrap();
+        let r = from_hex("ac8d1c87e51d0d441be8b3dd5b05c8795b48875dffe00b7ffcfac23010d3a395");
+        let z = Scalar::reduce_mod_n(-(&r * &e)).n;
+
+        for s in [
+            BigInt::one(),
+            BigInt::from(12345u32),
+            S256Point::order() - 1u8,
+        ] {
+            let sig = Signature::new_unchecked(r.clone(), s);
+            assert!(!point.verify(&z, &sig).unwrap());
+        }
+    }
+
+    #[test]
+    fn verify_reduces_r_x_mod_n() {
+        // R.x in [N, P) reduces to r = R.x - N; recovery id 2 lifts such an R back
+        let z = BigInt::from(7u8);
+        let s = BigInt::from(3u8);
+        let (r, point) = (1u32..)
+            .find_map(|r| {
+                let rsig = RecoverableSignature {
+                    sig: Signature::new_unchecked(BigInt::from(r), s.clone()),
+                    recovery_id: 2,
+                };
+                recover_public_key(&z, &rsig)
+                    .ok()
+                    .map(|q| (BigInt::from(r), q))
+            })
+            .unwrap();
+
+        assert!(point
+            .verify(&z, &Signature::new_unchecked(r.clone(), s.clone()))
+            .unwrap());
+        let unreduced = Signature::new_unchecked(r + S256Point::order(), s);
+        assert!(!point.verify(&z, &unreduced).unwrap());
+    }
+
+    #[test]
+    fn mul_by_small_integers() {
+        let g = S256Point::generator();
+        assert_eq!((&g * 2u32).unwrap(), (&g + &g).unwrap());
+        assert_eq!((&g * 2u64).unwrap(), (&g + &g).unwrap());
+        assert_eq!(
+            (&g * u64::MAX).unwrap(),
+            (Scalar::from(u64::MAX) * &g).unwrap()
+        );
+        assert_eq!((&g * 0u32).unwrap(), S256Point::new(None, None).unwrap());
+    }
+
+    #[test]
+    fn mul_reduces_scalar_mod_n() {
+        let g = S256Point::generator();
+        let k = BigInt::from(0xdead_beefu32);
+        let k_g = (Scalar::new(k.clone()) * &g).unwrap();
+        let (x, y) = k_g.coordinates();
+        let neg_k_g = S256Point::new(Some(x.clone()), Some(y.neg().unwrap())).unwrap();
+
+        assert_eq!((Scalar::new(-k.clone()) * &g).unwrap(), neg_k_g);
+        assert_eq!((Scalar::new(S256Point::order() + &k) * &g).unwrap(), k_g);
+    }
+
+    #[test]
+    fn add_assign_accumulates_generator_multiples() {
+        let g = S256Point::generator();
+        let mut acc = S256Point::new(None, None).unwrap();
+        for k in 1u8..=3 {
+            acc += &(Scalar::from(k) * &g).unwrap();
+        }
+        assert_eq!(acc, (Scalar::from(6u8) * &g).unwrap());
+
+        acc.try_add_assign(&g).unwrap();
+        assert_eq!(acc, (Scalar::from(7u8) * &g).unwrap());
+    }
+
+    #[test]
+    fn debug_prints_hex() {
+        assert_eq!(
+            format!("{:?}", S256Point::generator()),
+            "S256Point { \
+             x: 79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798, \
+             y: 483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8, \
+             infinity: false }"
+        );
+        assert_eq!(
+            format!("{:?}", S256Point::new(None, None).unwrap()),
+            "S256Point { infinity: true }"
+        );
+    }
+
+    #[test]
+    fn is_valid_public_key() {
+        let g = S256Point::generator();
+        assert!(g.is_valid_public_key());
+        assert!((Scalar::from(0xdeadbeefu32) * &g)
+            .unwrap()
+            .is_valid_public_key());
+
+        let infinity = S256Point::new(None, None).unwrap();
+        assert!(!infinity.is_valid_public_key());
+    }
+
+    #[test]
+    fn sort_pubkeys_matches_sec_order() {
+        let g = S256Point::generator();
+        let mut keys: Vec<S256Point> = [7u32, 3, 0xdead_beef, 1, 3, 42, 5000]
+            .into_iter()
+            .map(|k| (Scalar::from(k) * &g).unwrap())
+            .collect();
+        keys.push(S256Point::new(None, None).unwrap());
+
+        let mut expected: Vec<Vec<u8>> = keys[..7]
+            .iter()
+            .map(|k| k.to_sec_compressed().to_vec())
+            .collect();
+        expected.sort();
+
+        sort_pubkeys(&mut keys);
+        assert!(keys[0].x().is_none());
+        let sorted: Vec<Vec<u8>> = keys[1..]
+            .iter()
+            .map(|k| k.to_sec_compressed().to_vec())
+            .collect();
+        assert_eq!(sorted, expected);
+
+        // already sorted input is left as is
+        let before = keys.clone();
+        sort_pubkeys(&mut keys);
+        assert_eq!(keys, before);
+        assert_eq!(keys[0].cmp_lexicographic(&keys[0]), Ordering::Equal);
+    }
 }
@@ -1,15 +1,21 @@
-use std::fmt;
-use std::ops::{Add, Div, Mul, Sub};
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::ToString;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Div, Mul, Sub};
 
-use num_bigint::BigInt;
-use num_traits::Pow;
+use num_bigint::{BigInt, Sign};
+use num_traits::{One, Pow, Zero};
+use once_cell::race::OnceBox;
 
 use crate::ecc::abstractions::{ArithmeticResult, FieldElementTrait};
 use crate::ecc::error::FieldElementError;
 use crate::ecc::field_element::FieldElement;
+use crate::encoding::hex;
 
 /// `S256Field` concrete implementation of the FieldElement over prime field of 2**256 - 2**32 - 977
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct S256Field {
     field: FieldElement,
 }
@@ -30,26 +36,95 @@ impl FieldElementTrait for S256Field {
     }
 }
 
+static PRIME: OnceBox<BigInt> = OnceBox::new();
+static PRIME_MINUS_TWO: OnceBox<BigInt> = OnceBox::new();
+
 impl S256Field {
-    pub fn new(num: BigInt) -> S256Field {
-        // 2^256 - 2^32 - 977
-        let prime = BigInt::from(2u8)
-            .pow(256u32)
-            .sub(BigInt::from(2u8).pow(32u32))
-            .sub(BigInt::from(977u32));
-
-        Self {
-            field: FieldElement::from_values(num, prime).expect("weird"),
+    pub fn new(num: BigInt) -> ArithmeticResult<S256Field> {
+        Ok(Self {
+            field: FieldElement::from_values(num, Self::prime().clone())?,
+        })
+    }
+
+    /// secp256k1 field prime `2^256 - 2^32 - 977`, computed once on first use.
+    pub fn prime() -> &'static BigInt {
+        PRIME.get_or_init(|| {
+            Box::new(
+                BigInt::from(2u8)
+                    .pow(256u32)
+                    .sub(BigInt::from(2u8).pow(32u32))
+                    .sub(BigInt::from(977u32)),
+            )
+        })
+    }
+
+    /// Multiplicative inverse `self^(p - 2)` by Fermat's little theorem, with the exponent
+    /// computed once for the secp256k1 prime. Zero has no inverse.
+    pub fn inverse(&self) -> ArithmeticResult<S256Field> {
+        if self.get_num().is_zero() {
+            return Err(FieldElementError::InvalidField(
+                "Zero has no inverse modulo p.".to_string(),
+            ));
         }
+
+        let prime = self.get_prime();
+        let owned;
+        let exp = if prime == Self::prime() {
+            PRIME_MINUS_TWO.get_or_init(|| Box::new(Self::prime() - BigInt::from(2u8)))
+        } else {
+            owned = prime - BigInt::from(2u8);
+            &owned
+        };
+        S256Field::from_values(self.get_num().modpow(exp, prime), prime.clone())
     }
 
-    pub fn get_a() -> S256Field {
+    pub fn get_a() -> ArithmeticResult<S256Field> {
         S256Field::new(BigInt::from(0u8))
     }
 
-    pub fn get_b() -> S256Field {
+    pub fn get_b() -> ArithmeticResult<S256Field> {
         S256Field::new(BigInt::from(7u8))
     }
+
+    /// Big-endian 32-byte encoding, left-padded with zeros.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        let (_, bytes) = self.get_num().to_bytes_be();
+        let mut out = [0u8; 32];
+        out[32 - bytes.len()..].copy_from_slice(&bytes);
+        out
+    }
+
+    pub fn from_bytes(bytes: &[u8; 32]) -> ArithmeticResult<S256Field> {
+        S256Field::new(BigInt::from_bytes_be(Sign::Plus, bytes))
+    }
+
+    pub fn is_even(&self) -> bool {
+        !self.get_num().bit(0)
+    }
+
+    /// SEC compressed prefix for a y-coordinate: `0x02` when even, `0x03` when odd.
+    pub fn parity_byte(&self) -> u8 {
+        if self.is_even() {
+            0x02
+        } else {
+            0x03
+        }
+    }
+
+    /// Square root over secp256k1's prime. Since `p % 4 == 3` the root is simply
+    /// `w^((p + 1) / 4)`, no Tonelli-Shanks required.
+    pub fn sqrt(&self) -> ArithmeticResult<S256Field> {
+        let exp = (self.get_prime() + BigInt::one()) / BigInt::from(4u8);
+        let root = self.pow_mod_ref(&exp)?;
+
+        if root.square()? != *self {
+            return Err(FieldElementError::NotQuadraticResidue(format!(
+                "{} has no square root",
+                self.get_num()
+            )));
+        }
+        Ok(root)
+    }
 }
 
 impl fmt::Display for S256Field {
@@ -63,6 +138,13 @@ impl fmt::Display for S256Field {
     }
 }
 
+/// Zero-padded hex, so values line up with the hex test vectors they're compared against.
+impl fmt::Debug for S256Field {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "S256Field({})", hex::encode(&self.to_bytes()))
+    }
+}
+
 impl PartialEq for S256Field {
     fn eq(&self, other: &Self) -> bool {
         let num = self.field.get_num();
@@ -75,29 +157,47 @@ impl PartialEq for S256Field {
     }
 }
 
+impl Eq for S256Field {}
+
+impl Hash for S256Field {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.field.hash(state);
+    }
+}
+
+/// Succeeds only for an element of the secp256k1 field, i.e. when its prime is
+/// [`S256Field::prime`].
+impl TryFrom<FieldElement> for S256Field {
+    type Error = FieldElementError;
+
+    fn try_from(field: FieldElement) -> Result<Self, Self::Error> {
+        if field.get_prime() != S256Field::prime() {
+            return Err(FieldElementError::InvalidField(format!(
+                "prime {} is not the secp256k1 prime",
+                field.get_prime()
+            )));
+        }
+        Ok(S256Field { field })
+    }
+}
+
+impl From<S256Field> for FieldElement {
+    fn from(value: S256Field) -> Self {
+        value.field
+    }
+}
+
 impl Add for S256Field {
     type Output = ArithmeticResult<Self>;
 
     fn add(self, rhs: Self) -> Self::Output {
         self.check_primes(&rhs)?;
-        Ok(S256Field {
-            field: self.field.add(rhs.field)?,
-        })
+        let field = self.field.add(rhs.field)?;
+        Ok(S256Field { field })
     }
 }
 
-// impl<'a> Add<&'a S256Field> for S256Field {
-//     type Output = ArithmeticResult<Self>;
-//
-//     fn add(self, rhs: &'a S256Field) -> Self::Output {
-//         self.check_primes(&rhs)?;
-//         Ok(S256Field {
-//             field: self.field.add(&rhs.field)?,
-//         })
-//     }
-// }
-
-impl<'a, 'b> Add<&'b S256Field> for S256Field {
+impl<'b> Add<&'b S256Field> for S256Field {
     type Output = ArithmeticResult<Self>;
 
     fn add(self, rhs: &'b Self) -> Self::Output {
@@ -107,6 +207,16 @@ impl<'a, 'b> Add<&'b S256Field> for S256Field {
     }
 }
 
+impl<'b> Add<&'b S256Field> for &S256Field {
+    type Output = ArithmeticResult<S256Field>;
+
+    fn add(self, rhs: &'b S256Field) -> Self::Output {
+        self.check_primes(rhs)?;
+        let field = (&self.field).add(&rhs.field)?;
+        Ok(S256Field { field })
+    }
+}
+
 impl Sub for S256Field {
     type Output = ArithmeticResult<Self>;
 
@@ -117,27 +227,26 @@ impl Sub for S256Field {
     }
 }
 
-// impl<'a> Sub<&'a S256Field> for S256Field {
-//     type Output = ArithmeticResult<Self>;
-//
-//     fn sub(self, rhs: &'a S256Field) -> Self::Output {
-//         self.check_primes(&rhs)?;
-//         Ok(S256Field {
-//             field: self.field.sub(&rhs.field)?,
-//         })
-//     }
-// }
-
-impl<'a, 'b> Sub<&'b S256Field> for S256Field {
+impl<'b> Sub<&'b S256Field> for S256Field {
     type Output = ArithmeticResult<Self>;
 
     fn sub(self, rhs: &'b Self) -> Self::Output {
-        self.check_primes(&rhs)?;
+        self.check_primes(rhs)?;
         let field = self.field.sub(&rhs.field)?;
         Ok(S256Field { field })
     }
 }
 
+impl<'b> Sub<&'b S256Field> for &S256Field {
+    type Output = ArithmeticResult<S256Field>;
+
+    fn sub(self, rhs: &'b S256Field) -> Self::Output {
+        self.check_primes(rhs)?;
+        let field = (&self.field).sub(&rhs.field)?;
+        Ok(S256Field { field })
+    }
+}
+
 impl Mul for S256Field {
     type Output = ArithmeticResult<Self>;
 
@@ -148,54 +257,232 @@ impl Mul for S256Field {
     }
 }
 
-// impl<'a> Mul<&'a S256Field> for S256Field {
-//     type Output = ArithmeticResult<Self>;
-//
-//     fn mul(self, rhs: &'a S256Field) -> Self::Output {
-//         self.check_primes(&rhs)?;
-//         Ok(S256Field {
-//             field: self.field.mul(&rhs.field)?,
-//         })
-//     }
-// }
-
-impl<'a, 'b> Mul<&'b S256Field> for S256Field {
+impl<'b> Mul<&'b S256Field> for S256Field {
     type Output = ArithmeticResult<Self>;
 
     fn mul(self, rhs: &'b Self) -> Self::Output {
-        self.check_primes(&rhs)?;
+        self.check_primes(rhs)?;
         let field = self.field.mul(&rhs.field)?;
         Ok(S256Field { field })
     }
 }
 
+impl<'b> Mul<&'b S256Field> for &S256Field {
+    type Output = ArithmeticResult<S256Field>;
+
+    fn mul(self, rhs: &'b S256Field) -> Self::Output {
+        self.check_primes(rhs)?;
+        let field = (&self.field).mul(&rhs.field)?;
+        Ok(S256Field { field })
+    }
+}
+
 impl Div for S256Field {
     type Output = ArithmeticResult<Self>;
 
     fn div(self, rhs: Self) -> Self::Output {
         self.check_primes(&rhs)?;
-        let field = self.field.div(rhs.field)?;
-        Ok(S256Field { field })
+        self.mul(&rhs.inverse()?)
     }
 }
 
-// impl<'a> Div<&'a S256Field> for S256Field {
-//     type Output = ArithmeticResult<Self>;
-//
-//     fn div(self, rhs: &'a S256Field) -> Self::Output {
-//         self.check_primes(&rhs)?;
-//         Ok(S256Field {
-//             field: self.field.div(&rhs.field)?,
-//         })
-//     }
-// }
-
-impl<'a, 'b> Div<&'b S256Field> for S256Field {
+impl<'b> Div<&'b S256Field> for S256Field {
     type Output = ArithmeticResult<Self>;
 
     fn div(self, rhs: &'b Self) -> Self::Output {
-        self.check_primes(&rhs)?;
-        let field = self.field.div(&rhs.field)?;
-        Ok(S256Field { field })
+        self.check_primes(rhs)?;
+        self.mul(&rhs.inverse()?)
+    }
+}
+
+impl<'b> Div<&'b S256Field> for &S256Field {
+    type Output = ArithmeticResult<S256Field>;
+
+    fn div(self, rhs: &'b S256Field) -> Self::Output {
+        self.check_primes(rhs)?;
+        self.mul(&rhs.inverse()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecc::scalar::Scalar;
+
+    fn new_s256(num: u32) -> S256Field {
+        S256Field::new(BigInt::from(num)).unwrap()
+    }
+
+    #[test]
+    fn ref_ops_match_owned_ops() {
+        let a = new_s256(1234);
+        let b = new_s256(5678);
+
+        assert_eq!((&a + &b).unwrap(), (a.clone() + b.clone()).unwrap());
+        assert_eq!((&a - &b).unwrap(), (a.clone() - b.clone()).unwrap());
+        assert_eq!((&a * &b).unwrap(), (a.clone() * b.clone()).unwrap());
+        assert_eq!((&a / &b).unwrap(), (a.clone() / b.clone()).unwrap());
+
+        assert_eq!((a.clone() + &b).unwrap(), new_s256(6912));
+        assert_eq!((&b - &a).unwrap(), new_s256(4444));
+        assert_eq!((&a * &b).unwrap(), new_s256(1234 * 5678));
+        assert_eq!(((&a * &b).unwrap() / &b).unwrap(), a);
+    }
+
+    #[test]
+    fn sqrt_generator_y() {
+        let gy = S256Field::new(
+            BigInt::parse_bytes(
+                b"483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+                16,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let w = gy.pow_mod(BigInt::from(2u8));
+        let root = w.sqrt().unwrap();
+
+        assert_eq!(root.pow_mod(BigInt::from(2u8)), w);
+        assert!(root == gy || root == (new_s256(0) - &gy).unwrap());
+    }
+
+    #[test]
+    fn sqrt_non_residue() {
+        // -1 is never a square when p % 4 == 3
+        let minus_one = (new_s256(0) - new_s256(1)).unwrap();
+        assert!(matches!(
+            minus_one.sqrt(),
+            Err(FieldElementError::NotQuadraticResidue(_))
+        ));
+    }
+
+    #[test]
+    fn inverse() {
+        for num in [1u32, 2, 7, 1234, 0xdead_beef] {
+            let a = new_s256(num);
+            assert_eq!((&a * &a.inverse().unwrap()).unwrap(), new_s256(1));
+        }
+        assert!(matches!(
+            new_s256(0).inverse(),
+            Err(FieldElementError::InvalidField(_))
+        ));
+        assert!(matches!(
+            new_s256(5) / new_s256(0),
+            Err(FieldElementError::InvalidField(_))
+        ));
+    }
+
+    #[test]
+    fn neg() {
+        let a = new_s256(1234);
+        assert_eq!(a.neg().unwrap().neg().unwrap(), a);
+        assert_eq!(a.neg().unwrap(), (new_s256(0) - &a).unwrap());
+        assert_eq!(new_s256(0).neg().unwrap(), new_s256(0));
+    }
+
+    #[test]
+    fn prime_is_cached() {
+        let expected = BigInt::parse_bytes(
+            b"fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+            16,
+        )
+        .unwrap();
+        assert_eq!(*S256Field::prime(), expected);
+        assert!(core::ptr::eq(S256Field::prime(), S256Field::prime()));
+
+        let prime = S256Field::prime();
+        for i in 0..1_000_000u32 {
+            let fe = new_s256(i);
+            assert_eq!(fe.get_prime(), prime);
+        }
+    }
+
+    #[test]
+    fn new_rejects_out_of_range() {
+        assert!(S256Field::new(S256Field::prime().clone()).is_err());
+        assert!(S256Field::new(BigInt::from(-1)).is_err());
+        assert!(S256Field::new(S256Field::prime() - BigInt::one()).is_ok());
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let zero = new_s256(0);
+        assert_eq!(zero.to_bytes(), [0u8; 32]);
+        assert_eq!(S256Field::from_bytes(&[0u8; 32]).unwrap(), zero);
+
+        let fe = new_s256(0xdeadbeef);
+        let bytes = fe.to_bytes();
+        assert_eq!(bytes[28..], [0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(bytes[..28], [0u8; 28]);
+        assert_eq!(S256Field::from_bytes(&bytes).unwrap(), fe);
+
+        let max = S256Field::new(S256Field::prime() - BigInt::one()).unwrap();
+        assert_eq!(S256Field::from_bytes(&max.to_bytes()).unwrap(), max);
+    }
+
+    #[test]
+    fn from_bytes_rejects_values_above_prime() {
+        assert!(S256Field::from_bytes(&[0xff; 32]).is_err());
+
+        let (_, prime) = S256Field::prime().to_bytes_be();
+        assert!(S256Field::from_bytes(&prime.try_into().unwrap()).is_err());
+    }
+
+    #[test]
+    fn parity() {
+        let gy = S256Field::new(
+            BigInt::parse_bytes(
+                b"483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+                16,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(gy.is_even());
+        assert_eq!(gy.parity_byte(), 0x02);
+
+        let odd = (new_s256(0) - &gy).unwrap();
+        assert!(!odd.is_even());
+        assert_eq!(odd.parity_byte(), 0x03);
+    }
+
+    #[test]
+    fn square_matches_mul() {
+        let a = S256Field::new(S256Field::prime() - BigInt::from(12345u32)).unwrap();
+        assert_eq!(a.square().unwrap(), (&a * &a).unwrap());
+    }
+
+    #[test]
+    fn debug_prints_hex() {
+        let fe = S256Field::new(BigInt::from(0xbeefu32)).unwrap();
+        assert_eq!(
+            format!("{:?}", fe),
+            format!("S256Field({}beef)", "0".repeat(60))
+        );
+    }
+
+    #[test]
+    fn convert_from_field_element() {
+        let num = BigInt::from(0xdeadbeefu32);
+        let fe = FieldElement::from_values(num.clone(), S256Field::prime().clone()).unwrap();
+
+        let s256 = S256Field::try_from(fe.clone()).unwrap();
+        assert_eq!(s256, S256Field::new(num).unwrap());
+        assert_eq!(FieldElement::from(s256), fe);
+    }
+
+    #[test]
+    fn convert_from_field_element_rejects_other_primes() {
+        let fe = FieldElement::new(15, 223).unwrap();
+        assert!(matches!(
+            S256Field::try_from(fe),
+            Err(FieldElementError::InvalidField(_))
+        ));
+    }
+
+    #[test]
+    fn scalar_mul_by_ref() {
+        let a = new_s256(21);
+        assert_eq!((&Scalar::from(3u8) * &a).unwrap(), new_s256(63));
     }
 }
@@ -1,10 +1,17 @@
-use num_bigint::BigInt;
-use num_traits::{One, Zero};
-use std::ops::{Add, BitAnd, Mul};
+use alloc::format;
+use alloc::string::ToString;
+use core::fmt;
+use core::ops::{Add, Mul};
+use num_bigint::{BigInt, Sign};
+use num_traits::Zero;
+#[cfg(feature = "rand")]
+use rand_core::{CryptoRng, RngCore};
 
 use crate::ecc::abstractions::{ArithmeticResult, FieldElementTrait};
+use crate::ecc::error::FieldElementError;
+use crate::ecc::point::s256_point::S256Point;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Scalar {
     pub n: BigInt,
 }
@@ -18,6 +25,115 @@ impl Scalar {
     pub fn get_value(&self) -> &BigInt {
         &self.n
     }
+
+    /// Scalar that must already lie in `[1, N)`; unlike [`Scalar::new`] nothing is reduced.
+    pub fn new_checked(value: BigInt) -> ArithmeticResult<Scalar> {
+        if value <= BigInt::zero() || value >= *S256Point::order() {
+            return Err(FieldElementError::ScalarOutOfRange(format!(
+                "Scalar {} not in range 1 to {}",
+                value,
+                S256Point::order() - 1
+            )));
+        }
+        Ok(Scalar { n: value })
+    }
+
+    /// Parses a 32-byte big-endian secret scalar, which must lie in `[1, N)`.
+    pub fn from_bytes(bytes: &[u8; 32]) -> ArithmeticResult<Scalar> {
+        Scalar::new_checked(BigInt::from_bytes_be(Sign::Plus, bytes))
+    }
+
+    /// Uniformly random scalar in `[1, N)`.
+    ///
+    /// Draws 32 bytes and retries on `0` or values `>= N` instead of reducing mod `N`, which
+    /// would bias the result. `N` is close to `2^256`, so a retry is almost never needed.
+    #[cfg(feature = "rand")]
+    pub fn random<R: RngCore + CryptoRng>(rng: &mut R) -> Scalar {
+        let mut bytes = [0u8; 32];
+        loop {
+            rng.fill_bytes(&mut bytes);
+            if let Ok(scalar) = Scalar::from_bytes(&bytes) {
+                return scalar;
+            }
+        }
+    }
+
+    /// 32-byte big-endian encoding, left-padded with zeros.
+    ///
+    /// Panics if the value is negative or does not fit in 256 bits.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        let (sign, bytes) = self.n.to_bytes_be();
+        assert!(
+            sign != Sign::Minus && bytes.len() <= 32,
+            "scalar does not fit in 32 bytes"
+        );
+        let mut out = [0u8; 32];
+        out[32 - bytes.len()..].copy_from_slice(&bytes);
+        out
+    }
+
+    /// Whether the value, reduced mod `N`, is in the upper half of the order: `n > N/2`.
+    pub fn is_high(&self) -> bool {
+        let half_n: BigInt = S256Point::order() >> 1;
+        Scalar::reduce_mod_n(self.n.clone()).n > half_n
+    }
+
+    /// `(self + other) mod N`
+    pub fn add_mod_n(&self, other: &Scalar) -> Scalar {
+        Scalar::reduce_mod_n(&self.n + &other.n)
+    }
+
+    /// `(self - other) mod N`, always in `[0, N)`.
+    pub fn sub_mod_n(&self, other: &Scalar) -> Scalar {
+        Scalar::reduce_mod_n(&self.n - &other.n)
+    }
+
+    /// `-self mod N` when `cond` holds, otherwise `self mod N`. BIP340 and BIP341 negate a
+    /// secret whenever its point has an odd y, see [`S256Point::has_even_y`].
+    pub fn negate_if(&self, cond: bool) -> Scalar {
+        if cond {
+            Scalar::reduce_mod_n(-&self.n)
+        } else {
+            Scalar::reduce_mod_n(self.n.clone())
+        }
+    }
+
+    /// `(self * other) mod N`
+    pub fn mul_mod_n(&self, other: &Scalar) -> Scalar {
+        Scalar::reduce_mod_n(&self.n * &other.n)
+    }
+
+    /// Multiplicative inverse modulo the group order, `self^(N - 2) mod N`.
+    pub fn inverse_mod_n(&self) -> ArithmeticResult<Scalar> {
+        let n = S256Point::order();
+        let value = Scalar::reduce_mod_n(self.n.clone());
+        if value.n.is_zero() {
+            return Err(FieldElementError::InvalidField(
+                "Zero has no inverse modulo N.".to_string(),
+            ));
+        }
+        Ok(Scalar::new(modpow_n(&value.n, &(n - BigInt::from(2u8)))))
+    }
+
+    /// `value mod N`, always in `[0, N)`, for hash outputs and products that routinely
+    /// exceed the group order.
+    pub fn reduce_mod_n(value: BigInt) -> Scalar {
+        let n = S256Point::order();
+        let mut value = value % n;
+        if value < BigInt::zero() {
+            value += n;
+        }
+        Scalar::new(value)
+    }
+}
+
+/// `base^exp mod N` over the group order, for scalar arithmetic. Field exponentiation mod
+/// `p` is [`FieldElementTrait::pow_mod`]; the two moduli are easy to mix up, so they stay
+/// separate. `base` may be negative; `exp` must not be.
+pub fn modpow_n(base: &BigInt, exp: &BigInt) -> BigInt {
+    Scalar::reduce_mod_n(base.clone())
+        .n
+        .modpow(exp, S256Point::order())
 }
 
 impl From<u8> for Scalar {
@@ -36,56 +152,104 @@ impl From<u32> for Scalar {
     }
 }
 
+impl From<u64> for Scalar {
+    fn from(value: u64) -> Self {
+        Self {
+            n: BigInt::from(value),
+        }
+    }
+}
+
+impl From<u128> for Scalar {
+    fn from(value: u128) -> Self {
+        Self {
+            n: BigInt::from(value),
+        }
+    }
+}
+
+impl From<usize> for Scalar {
+    fn from(value: usize) -> Self {
+        Self {
+            n: BigInt::from(value),
+        }
+    }
+}
+
+/// Negative values are reduced modulo `N`, so `-1` becomes `N - 1`.
+impl From<i32> for Scalar {
+    fn from(value: i32) -> Self {
+        if value < 0 {
+            Scalar::reduce_mod_n(BigInt::from(value))
+        } else {
+            Self {
+                n: BigInt::from(value),
+            }
+        }
+    }
+}
+
+impl From<BigInt> for Scalar {
+    fn from(value: BigInt) -> Self {
+        Self { n: value }
+    }
+}
+
+/// Hex without leading zeros; `{:#}` pads to the full 64 digits. Width and fill flags apply
+/// as for integers, e.g. `{:064}`.
+impl fmt::Display for Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{:064x}", self.n)
+        } else {
+            fmt::LowerHex::fmt(&self.n, f)
+        }
+    }
+}
+
+impl fmt::LowerHex for Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.n, f)
+    }
+}
+
 impl<F: FieldElementTrait + Clone> Mul<&F> for Scalar {
     type Output = ArithmeticResult<F>;
 
     fn mul(self, rhs: &F) -> Self::Output {
-        let prime = rhs.get_prime();
-        let num = rhs.get_num();
-
-        let mut coef = self.get_value() - &BigInt::one();
         let mut current = rhs.clone();
-        let mut result = F::from_values(num.clone(), prime.clone())?;
+        let mut result = rhs.zero()?;
 
-        while coef > BigInt::zero() {
-            if coef.clone().bitand(BigInt::one()) == BigInt::one() {
-                let new_result = (result + &current)?;
-                result = new_result;
+        for i in 0..self.n.bits() {
+            if self.n.bit(i) {
+                result = (result + &current)?;
             }
-            let new_current = (current.clone() + current)?;
-            current = new_current;
-            coef >>= 1;
+            current = (current.clone() + &current)?;
         }
 
         Ok(result)
     }
 }
 
-impl<'a, 'b, F: FieldElementTrait + Clone> Mul<&'b F> for &'a Scalar
+impl<F: FieldElementTrait + Clone> Mul<&F> for &Scalar
 where
-    &'b F: Add<&'b F, Output = ArithmeticResult<F>>,
+    for<'x> &'x F: Add<&'x F, Output = ArithmeticResult<F>>,
 {
     type Output = ArithmeticResult<F>;
 
-    fn mul(self, rhs: &'b F) -> Self::Output {
-        let prime = rhs.get_prime();
-        let num = rhs.get_num();
-
-        let mut coef = self.n.clone() - BigInt::one(); // No need for a reference here
-        let one = BigInt::one();
-        let zero = BigInt::zero();
-
-        let mut current = rhs.clone();
-        let mut result = F::from_values(num.clone(), prime.clone())?;
+    fn mul(self, rhs: &F) -> Self::Output {
+        let bits = self.n.bits();
+        if bits == 0 {
+            return rhs.zero();
+        }
 
-        while &coef > &zero {
-            if coef.clone().bitand(&one) == one {
-                let new_result = (result + &current)?;
-                result = new_result;
+        // walk the bits from the top so only `result` changes and `rhs` is never copied
+        let mut result = rhs.clone();
+        for i in (0..bits - 1).rev() {
+            result = (&result + &result)?;
+            if self.n.bit(i) {
+                result = (&result + rhs)?;
             }
-            let new_current = (current.clone() + current)?;
-            current = new_current;
-            coef >>= 1;
         }
 
         Ok(result)
@@ -94,6 +258,8 @@ where
 
 #[cfg(test)]
 mod tests {
+    use num_traits::One;
+
     use crate::ecc::field_element::FieldElement;
 
     use super::*;
@@ -102,6 +268,197 @@ mod tests {
         FieldElement::new(num, prime).unwrap()
     }
 
+    fn order() -> BigInt {
+        S256Point::order().clone()
+    }
+
+    #[test]
+    fn display_as_hex() {
+        let a = Scalar::from(255u8);
+        assert_eq!(a.to_string(), "ff");
+        assert_eq!(format!("{:x}", a), "ff");
+        assert_eq!(format!("{:#x}", a), "0xff");
+        assert_eq!(format!("{:06}", a), "0000ff");
+        assert_eq!(format!("{:#}", a), format!("{}ff", "0".repeat(62)));
+        assert_eq!(
+            format!("{:#}", Scalar::new(order() - 1)),
+            "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140"
+        );
+    }
+
+    #[test]
+    fn negate_if() {
+        let a = Scalar::from(7u8);
+        assert_eq!(a.negate_if(true), Scalar::new(order() - 7));
+        assert_eq!(a.negate_if(false), a);
+        assert_eq!(a.negate_if(true).negate_if(true), a);
+        assert_eq!(Scalar::from(0u8).negate_if(true), Scalar::from(0u8));
+        assert_eq!(Scalar::new(order() + 7).negate_if(false), a);
+    }
+
+    #[test]
+    fn reduce_mod_n_and_new_checked() {
+        let n = order();
+        assert_eq!(Scalar::reduce_mod_n(n.clone()), Scalar::from(0u8));
+        assert_eq!(Scalar::reduce_mod_n(&n + 1u8), Scalar::from(1u8));
+        assert_eq!(Scalar::reduce_mod_n(&n * 2u8), Scalar::from(0u8));
+        assert_eq!(Scalar::reduce_mod_n(-BigInt::one()), Scalar::new(&n - 1u8));
+
+        for value in [
+            BigInt::zero(),
+            n.clone(),
+            &n + 1u8,
+            &n * 2u8,
+            -BigInt::one(),
+        ] {
+            assert!(matches!(
+                Scalar::new_checked(value),
+                Err(FieldElementError::ScalarOutOfRange(_))
+            ));
+        }
+        assert_eq!(
+            Scalar::new_checked(&n - 1u8).unwrap(),
+            Scalar::new(&n - 1u8)
+        );
+    }
+
+    #[test]
+    fn arithmetic_mod_n() {
+        let a = Scalar::new(order() - BigInt::from(5u8));
+        let b = Scalar::from(7u8);
+
+        assert_eq!(a.add_mod_n(&b), Scalar::from(2u8));
+        assert_eq!(b.sub_mod_n(&Scalar::from(9u8)), Scalar::new(order() - 2));
+        assert_eq!(a.sub_mod_n(&b), Scalar::new(order() - 12));
+        assert_eq!(a.mul_mod_n(&b), Scalar::new(order() - 35));
+    }
+
+    #[test]
+    fn inverse_mod_n() {
+        let a = Scalar::new(order() - BigInt::from(1234u32));
+        let values = [
+            Scalar::from(2u8),
+            Scalar::from(0xdeadbeefu32),
+            Scalar::new(order() - BigInt::one()),
+        ];
+
+        for b in values {
+            let b_inv = b.inverse_mod_n().unwrap();
+            assert_eq!(b.mul_mod_n(&b_inv), Scalar::from(1u8));
+            assert_eq!(a.mul_mod_n(&b).mul_mod_n(&b_inv), a);
+        }
+
+        assert!(Scalar::from(0u8).inverse_mod_n().is_err());
+        assert!(Scalar::new(order()).inverse_mod_n().is_err());
+    }
+
+    #[test]
+    fn modpow_n_matches_bigint_modpow() {
+        let n = order();
+        let exp = &n - 2u8;
+        for value in [2u32, 1234, 0xdead_beef] {
+            let b = BigInt::from(value);
+            assert_eq!(
+                Scalar::from(value).inverse_mod_n().unwrap().n,
+                b.modpow(&exp, &n)
+            );
+            assert_eq!(modpow_n(&b, &exp), b.modpow(&exp, &n));
+        }
+        assert_eq!(modpow_n(&BigInt::from(-1), &BigInt::from(3u8)), &n - 1u8);
+        assert_eq!(modpow_n(&n, &BigInt::one()), BigInt::zero());
+    }
+
+    #[test]
+    fn is_high() {
+        let half: BigInt = order() >> 1;
+        assert!(!Scalar::new(half.clone()).is_high());
+        assert!(Scalar::new(&half + 1).is_high());
+        assert!(!Scalar::from(1u8).is_high());
+        assert!(Scalar::new(order() - 1).is_high());
+
+        // reduced mod N first
+        assert!(!Scalar::new(order()).is_high());
+        assert!(!Scalar::new(order() + &half).is_high());
+        assert!(Scalar::from(-1i32).is_high());
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let values = [
+            BigInt::one(),
+            BigInt::from(0xdeadbeefu32),
+            order() - BigInt::one(),
+        ];
+        for value in values {
+            let scalar = Scalar::new(value);
+            let bytes = scalar.to_bytes();
+            assert_eq!(Scalar::from_bytes(&bytes).unwrap(), scalar);
+        }
+
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        assert_eq!(Scalar::from(1u8).to_bytes(), one);
+        assert_eq!(Scalar::from(0u8).to_bytes(), [0u8; 32]);
+    }
+
+    #[test]
+    fn from_bytes_rejects_zero_and_order() {
+        assert!(matches!(
+            Scalar::from_bytes(&[0u8; 32]),
+            Err(FieldElementError::ScalarOutOfRange(_))
+        ));
+        assert!(matches!(
+            Scalar::from_bytes(&Scalar::new(order()).to_bytes()),
+            Err(FieldElementError::ScalarOutOfRange(_))
+        ));
+        assert!(Scalar::from_bytes(&[0xff; 32]).is_err());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random_is_in_range() {
+        let mut rng = rand_core::OsRng;
+        for _ in 0..16 {
+            let scalar = Scalar::random(&mut rng);
+            assert!(scalar.n > BigInt::zero() && scalar.n < order());
+        }
+    }
+
+    #[test]
+    fn from_integer_types() {
+        let g = S256Point::generator();
+        let expected = (Scalar::new(BigInt::from(840_000u32)) * &g).unwrap();
+
+        let scalars = [
+            Scalar::from(840_000u32),
+            Scalar::from(840_000u64),
+            Scalar::from(840_000u128),
+            Scalar::from(840_000usize),
+            Scalar::from(840_000i32),
+            Scalar::from(BigInt::from(840_000u32)),
+        ];
+        for scalar in scalars {
+            assert_eq!(scalar.n, BigInt::from(840_000u32));
+            assert_eq!((scalar * &g).unwrap(), expected);
+        }
+
+        assert_eq!(Scalar::from(u64::MAX).n, BigInt::from(u64::MAX));
+        assert_eq!(Scalar::from(u128::MAX).n, BigInt::from(u128::MAX));
+    }
+
+    #[test]
+    fn from_negative_i32_reduces_mod_n() {
+        assert_eq!(Scalar::from(-1i32), Scalar::new(order() - 1));
+        assert_eq!(Scalar::from(i32::MIN), Scalar::new(order() + i32::MIN));
+
+        // -k * G is the negation of k * G
+        let g = S256Point::generator();
+        let neg = (Scalar::from(-7i32) * &g).unwrap();
+        let pos = (Scalar::from(7i32) * &g).unwrap();
+        assert_eq!(neg.x(), pos.x());
+        assert_ne!(neg.y(), pos.y());
+    }
+
     #[test]
     fn multiply_field_element() {
         let fe = new_fe(15, 223);
@@ -112,4 +469,26 @@ mod tests {
 
         assert_eq!((scalar * &fe).unwrap(), new_fe(30, 223));
     }
+
+    #[test]
+    fn multiply_by_zero_and_one() {
+        let fe = new_fe(15, 223);
+
+        assert_eq!((&Scalar::from(0u8) * &fe).unwrap(), new_fe(0, 223));
+        assert_eq!((Scalar::from(0u8) * &fe).unwrap(), new_fe(0, 223));
+        assert_eq!((&Scalar::from(1u8) * &fe).unwrap(), fe);
+        assert_eq!((Scalar::from(1u8) * &fe).unwrap(), fe);
+
+        // 15 * 20 = 300 = 77 (mod 223)
+        assert_eq!((Scalar::from(20u8) * &fe).unwrap(), new_fe(77, 223));
+    }
+
+    #[test]
+    fn multiply_point_by_zero_and_one() {
+        let g = S256Point::generator();
+
+        let zero = (Scalar::from(0u8) * &g).unwrap();
+        assert!(zero.x().is_none() && zero.y().is_none());
+        assert_eq!((Scalar::from(1u8) * &g).unwrap(), g);
+    }
 }
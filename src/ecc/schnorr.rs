@@ -0,0 +1,255 @@
+use alloc::vec::Vec;
+
+use num_bigint::{BigInt, Sign};
+use num_traits::Zero;
+
+use crate::ecc::abstractions::FieldElementTrait;
+use crate::ecc::point::s256_point::S256Point;
+use crate::ecc::s256_field::S256Field;
+use crate::ecc::scalar::Scalar;
+use crate::hash::tagged_hash;
+
+/// BIP340 Schnorr signature of `msg` under `secret`, returned as `bytes(R.x) || bytes(s)`.
+///
+/// Panics if `secret` is not in `[1, N)`.
+pub fn schnorr_sign(secret: &Scalar, msg: &[u8; 32], aux_rand: &[u8; 32]) -> [u8; 64] {
+    let n = S256Point::order();
+    let d0 = secret.get_value();
+    assert!(
+        d0 > &BigInt::zero() && d0 < n,
+        "secret must be in the range [1, N)"
+    );
+
+    let p = mul_generator(d0);
+    let px = p.to_x_only();
+    // BIP340 public keys are x-only with implicitly even y, so negate d for an odd y
+    let d = secret.negate_if(!p.has_even_y());
+
+    let aux = tagged_hash("BIP0340/aux", aux_rand);
+    let t: Vec<u8> = d
+        .to_bytes()
+        .iter()
+        .zip(aux.iter())
+        .map(|(a, b)| a ^ b)
+        .collect();
+    let rand = tagged_hash("BIP0340/nonce", &[&t[..], &px, msg].concat());
+
+    let k0 = BigInt::from_bytes_be(Sign::Plus, &rand) % n;
+    assert!(!k0.is_zero(), "nonce k is zero");
+
+    let r = mul_generator(&k0);
+    let rx = r.x().unwrap();
+    let k = Scalar::new(k0).negate_if(!r.has_even_y());
+
+    let e = challenge(&rx.to_bytes(), &px, msg);
+    let s = Scalar::new((k.n + e * &d.n) % n);
+
+    let mut sig = [0u8; 64];
+    sig[..32].copy_from_slice(&rx.to_bytes());
+    sig[32..].copy_from_slice(&s.to_bytes());
+    sig
+}
+
+/// Verifies a BIP340 signature against an x-only public key.
+pub fn schnorr_verify(pubkey_x: &[u8; 32], msg: &[u8; 32], sig: &[u8; 64]) -> bool {
+    let n = S256Point::order();
+    let p = match S256Point::lift_x(pubkey_x) {
+        Ok(p) => p,
+        Err(_) => return false,
+    };
+
+    let r = BigInt::from_bytes_be(Sign::Plus, &sig[..32]);
+    let s = BigInt::from_bytes_be(Sign::Plus, &sig[32..]);
+    if &r >= S256Field::prime() || &s >= n {
+        return false;
+    }
+
+    let e = challenge(sig[..32].try_into().unwrap(), pubkey_x, msg);
+
+    // R = s*G - e*P
+    let sg = mul_generator(&s);
+    let ep = match Scalar::new((n - e) % n) * &p {
+        Ok(ep) => ep,
+        Err(_) => return false,
+    };
+    let big_r = match sg + ep {
+        Ok(big_r) => big_r,
+        Err(_) => return false,
+    };
+
+    match big_r.x() {
+        Some(x) => big_r.has_even_y() && *x.get_num() == r,
+        None => false,
+    }
+}
+
+/// `e = hash_BIP0340/challenge(R.x || P.x || m) mod N`
+fn challenge(rx: &[u8; 32], px: &[u8; 32], msg: &[u8; 32]) -> BigInt {
+    let hash = tagged_hash("BIP0340/challenge", &[&rx[..], px, msg].concat());
+    BigInt::from_bytes_be(Sign::Plus, &hash) % S256Point::order()
+}
+
+fn mul_generator(k: &BigInt) -> S256Point {
+    (Scalar::new(k.clone()) * &S256Point::generator()).expect("G is on secp256k1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::hex;
+
+    fn bytes<const N: usize>(input: &str) -> [u8; N] {
+        hex::decode(input).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn bip340_signing_vectors() {
+        // (secret key, public key, aux_rand, message, signature)
+        let vectors = [
+            (
+                "0000000000000000000000000000000000000000000000000000000000000003",
+                "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA8215\
+                 25F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0",
+            ),
+            (
+                "B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF",
+                "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE3341\
+                 8906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A",
+            ),
+            (
+                "C90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74020BBEA63B14E5C9",
+                "DD308AFEC5777E13121FA72B9CC1B7CC0139715309B086C960E18FD969774EB8",
+                "C87AA53824B4D7AE2EB035A2B5BBBCCC080E76CDC6D1692C4B0B62D798E6D906",
+                "7E2D58D8B3BCDF1ABADEC7829054F90DDA9805AAB56C77333024B9D0A508B75C",
+                "5831AAEED7B44BB74E5EAB94BA9D4294C49BCF2A60728D8B4C200F50DD313C1B\
+                 AB745879A5AD954A72C45A91C3A51D3C7ADEA98D82F8481E0E1E03674A6F3FB7",
+            ),
+            (
+                "0B432B2677937381AEF05BB02A66ECD012773062CF3FA2549E44F58ED2401710",
+                "25D1DFF95105F5253C4022F628A996AD3A0D95FBF21D468A1B33F8C160D8F517",
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+                "7EB0509757E246F19449885651611CB965ECC1A187DD51B64FDA1EDC9637D5EC\
+                 97582B9CB13DB3933705B32BA982AF5AF25FD78881EBB32771FC5922EFC66EA3",
+            ),
+        ];
+
+        for (secret, pubkey, aux, msg, sig) in vectors {
+            let secret = Scalar::new(BigInt::from_bytes_be(Sign::Plus, &bytes::<32>(secret)));
+            let (pubkey, aux, msg, sig) = (bytes(pubkey), bytes(aux), bytes(msg), bytes(sig));
+
+            assert_eq!(mul_generator(secret.get_value()).to_x_only(), pubkey);
+            assert_eq!(schnorr_sign(&secret, &msg, &aux), sig);
+            assert!(schnorr_verify(&pubkey, &msg, &sig));
+        }
+    }
+
+    #[test]
+    fn bip340_verification_vectors() {
+        // (public key, message, signature, valid)
+        let vectors = [
+            (
+                "D69C3509BB99E412E68B0FE8544E72837DFA30746D8BE2AA65975F29D22DC7B9",
+                "4DF3C3F68FCC83B27E9D42C90431A72499F17875C81A599B566C9889B9696703",
+                "00000000000000000000003B78CE563F89A0ED9414F5AA28AD0D96D6795F9C63\
+                 76AFB1548AF603B3EB45C9F8207DEE1060CB71C04E80F593060B07D28308D7F4",
+                true,
+            ),
+            // public key not on the curve
+            (
+                "EEFDEA4CDB677750A420FEE807EACF21EB9898AE79B9768766E4FAA04A2D4A34",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769\
+                 69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+                false,
+            ),
+            // has_even_y(R) is false
+            (
+                "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "FFF97BD5755EEEA420453A14355235D382F6472F8568A18B2F057A1460297556\
+                 3CC27944640AC607CD107AE10923D9EF7A73C643E166BE5EBEAFA34B1AC553E2",
+                false,
+            ),
+            // negated message
+            (
+                "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "1FA62E331EDBC21C394792D2AB1100A7B432B013DF3F6FF4F99FCB33E0E1515F\
+                 28890B3EDB6E7189B630448B515CE4F8622A954CFE545735AAEA5134FCCDB2BD",
+                false,
+            ),
+            // negated s value
+            (
+                "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769\
+                 961764B3AA9B2FFCB6EF947B6887A226E8D7C93E00C5ED0C1834FF0D0C2E6DA6",
+                false,
+            ),
+            // sig[0:32] is not an x coordinate on the curve
+            (
+                "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "4A298DACAE57395A15D0795DDBFD1DCB564DA82B0F269BC70A74F8220429BA1D\
+                 69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+                false,
+            ),
+            // sig[0:32] (r) is equal to the field size p
+            (
+                "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F\
+                 69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+                false,
+            ),
+            // sig[32:64] (s) is equal to the curve order N
+            (
+                "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769\
+                 FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+                false,
+            ),
+            // public key is not a valid x coordinate because it exceeds the field size
+            (
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC30",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769\
+                 69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+                false,
+            ),
+        ];
+
+        for (pubkey, msg, sig, valid) in vectors {
+            assert_eq!(
+                schnorr_verify(&bytes(pubkey), &bytes(msg), &bytes(sig)),
+                valid
+            );
+        }
+    }
+
+    #[test]
+    fn verify_rejects_tampered_signature() {
+        let secret = Scalar::from(3u8);
+        let msg = [0x42u8; 32];
+        let pubkey = mul_generator(secret.get_value()).to_x_only();
+
+        let mut sig = schnorr_sign(&secret, &msg, &[0u8; 32]);
+        assert!(schnorr_verify(&pubkey, &msg, &sig));
+
+        sig[63] ^= 0x01;
+        assert!(!schnorr_verify(&pubkey, &msg, &sig));
+
+        // s >= N
+        let mut sig = schnorr_sign(&secret, &msg, &[0u8; 32]);
+        sig[32..].copy_from_slice(&Scalar::new(S256Point::order().clone()).to_bytes());
+        assert!(!schnorr_verify(&pubkey, &msg, &sig));
+    }
+}
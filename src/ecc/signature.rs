@@ -0,0 +1,1051 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::fmt;
+use core::fmt::{Display, Formatter};
+
+use num_bigint::{BigInt, Sign};
+use num_traits::{Signed, Zero};
+
+use crate::ecc::error::{FieldElementError, SignatureError};
+use crate::ecc::point::s256_point::S256Point;
+use crate::ecc::s256_field::S256Field;
+use crate::ecc::scalar::Scalar;
+use crate::hash::hash256;
+
+const MIN_DER_LEN: usize = 8;
+const MAX_DER_LEN: usize = 72;
+
+/// Sighash type committing to all inputs and outputs, the usual trailing byte of a
+/// scriptSig signature.
+pub const SIGHASH_ALL: u8 = 0x01;
+
+/// ECDSA signature `(r, s)` over secp256k1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Signature {
+    pub r: BigInt,
+    pub s: BigInt,
+}
+
+impl Signature {
+    /// Signature from its scalars, both of which must lie in `[1, N)`.
+    pub fn new(r: BigInt, s: BigInt) -> Result<Signature, SignatureError> {
+        if r.is_zero() || s.is_zero() {
+            return Err(SignatureError::ZeroScalar(
+                "r and s must be non-zero".to_string(),
+            ));
+        }
+        let n = S256Point::order();
+        if r.is_negative() || s.is_negative() || &r >= n || &s >= n {
+            return Err(SignatureError::ScalarOutOfRange(format!(
+                "r and s must be in [1, {:x})",
+                n
+            )));
+        }
+        Ok(Signature { r, s })
+    }
+
+    /// Signature from scalars already known to be in range, or deliberately out of it.
+    pub(crate) fn new_unchecked(r: BigInt, s: BigInt) -> Self {
+        Self { r, s }
+    }
+
+    /// Whether `s` is in the lower half of the order, as required by BIP 62 relay rules.
+    pub fn is_low_s(&self) -> bool {
+        !Scalar::new(self.s.clone()).is_high()
+    }
+
+    /// Rejects a signature whose `s` is in the upper half of the order.
+    pub fn ensure_low_s(&self) -> Result<(), SignatureError> {
+        if !self.is_low_s() {
+            return Err(SignatureError::HighS(format!("s = {:x}", self.s)));
+        }
+        Ok(())
+    }
+
+    /// Replaces a high `s` with `N - s`. Both values verify, so this only picks the
+    /// canonical one.
+    pub fn normalize_s(&mut self) {
+        if Scalar::new(self.s.clone()).is_high() {
+            self.s = S256Point::order() - &self.s;
+        }
+    }
+
+    /// DER encoding: `0x30 len 0x02 rlen r 0x02 slen s`.
+    pub fn to_der(&self) -> Vec<u8> {
+        let r = der_integer(&self.r);
+        let s = der_integer(&self.s);
+
+        let mut out = vec![0x30, (r.len() + s.len()) as u8];
+        out.extend(r);
+        out.extend(s);
+        out
+    }
+
+    /// DER encoding followed by the sighash type byte, as the signature appears in a
+    /// scriptSig or witness.
+    pub fn to_der_with_sighash(&self, sighash_type: u8) -> Vec<u8> {
+        let mut out = self.to_der();
+        out.push(sighash_type);
+        out
+    }
+
+    /// Splits the trailing sighash type byte off a transaction signature and parses the DER
+    /// blob in front of it with [`Signature::from_der`].
+    pub fn from_der_with_sighash(bytes: &[u8]) -> Result<(Signature, u8), SignatureError> {
+        let Some((&sighash_type, der)) = bytes.split_last() else {
+            return Err(SignatureError::InvalidLength(
+                "empty signature has no sighash byte".to_string(),
+            ));
+        };
+        if der.len() < 2 || der[1] as usize + 2 != der.len() {
+            return Err(SignatureError::InvalidDer(format!(
+                "DER prefix of {} bytes does not end right before the sighash byte",
+                der.len()
+            )));
+        }
+        Ok((Signature::from_der(der)?, sighash_type))
+    }
+
+    /// Parses a DER signature in the canonical form consensus code relies on: BIP66 strict
+    /// encoding (no padding, no negative integers, exact lengths) and a low `s` as in BIP 62.
+    /// Every encoding this accepts maps to a single signature, so it can't be malleated.
+    pub fn from_der_strict(bytes: &[u8]) -> Result<Signature, SignatureError> {
+        check_bip66(bytes)?;
+        let sig = Signature::from_der(bytes)?;
+        if !sig.is_low_s() {
+            return Err(SignatureError::NonCanonical(format!(
+                "high s = {:x}",
+                sig.s
+            )));
+        }
+        Ok(sig)
+    }
+
+    /// Fixed-size encoding: 32-byte big-endian `r` followed by 32-byte big-endian `s`.
+    ///
+    /// Panics if `r` or `s` is negative or does not fit in 32 bytes.
+    pub fn to_compact(&self) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        out[..32].copy_from_slice(&Scalar::new(self.r.clone()).to_bytes());
+        out[32..].copy_from_slice(&Scalar::new(self.s.clone()).to_bytes());
+        out
+    }
+
+    /// Parses the `r || s` encoding from [`Signature::to_compact`]. Both scalars must be
+    /// in `[1, N)`.
+    pub fn from_compact(bytes: &[u8; 64]) -> Result<Signature, SignatureError> {
+        Signature::new(
+            BigInt::from_bytes_be(Sign::Plus, &bytes[..32]),
+            BigInt::from_bytes_be(Sign::Plus, &bytes[32..]),
+        )
+    }
+
+    /// Parses a DER signature as produced by [`Signature::to_der`]. Trailing bytes,
+    /// negative integers and long-form lengths are rejected, and `r` and `s` must be in
+    /// `[1, N)` as for [`Signature::new`].
+    pub fn from_der(bytes: &[u8]) -> Result<Signature, SignatureError> {
+        // two minimal one-byte integers at the least, two 33-byte ones at the most
+        if !(MIN_DER_LEN..=MAX_DER_LEN).contains(&bytes.len()) {
+            return Err(SignatureError::InvalidLength(format!(
+                "DER signature is {} bytes, expected {} to {}",
+                bytes.len(),
+                MIN_DER_LEN,
+                MAX_DER_LEN
+            )));
+        }
+        if bytes.first() != Some(&0x30) {
+            return Err(SignatureError::InvalidDer(
+                "expected sequence tag 0x30".to_string(),
+            ));
+        }
+        let len = der_length(bytes, 1)?;
+        if bytes.len() != 2 + len {
+            return Err(SignatureError::InvalidDer(format!(
+                "sequence length {} does not match {} remaining bytes",
+                len,
+                bytes.len() - 2
+            )));
+        }
+
+        let (r, pos) = parse_der_integer(bytes, 2)?;
+        let (s, pos) = parse_der_integer(bytes, pos)?;
+        if pos != bytes.len() {
+            return Err(SignatureError::InvalidDer(
+                "trailing bytes after s".to_string(),
+            ));
+        }
+
+        Signature::new(r, s)
+    }
+}
+
+/// BIP66 strict DER check (without the trailing sighash byte), following Bitcoin Core's
+/// `IsValidSignatureEncoding`.
+fn check_bip66(sig: &[u8]) -> Result<(), SignatureError> {
+    let non_canonical = |reason: &str| Err(SignatureError::NonCanonical(reason.to_string()));
+
+    let len = sig.len();
+    if !(MIN_DER_LEN..=MAX_DER_LEN).contains(&len) {
+        return non_canonical("wrong total length");
+    }
+    if sig[0] != 0x30 {
+        return non_canonical("expected sequence tag 0x30");
+    }
+    if sig[1] as usize != len - 2 {
+        return non_canonical("sequence length does not cover the signature");
+    }
+
+    let len_r = sig[3] as usize;
+    if 5 + len_r >= len {
+        return non_canonical("r overruns the signature");
+    }
+    let len_s = sig[5 + len_r] as usize;
+    if len_r + len_s + 6 != len {
+        return non_canonical("r and s lengths do not add up");
+    }
+
+    for (tag, start, int_len) in [(2, 4, len_r), (len_r + 4, len_r + 6, len_s)] {
+        if sig[tag] != 0x02 {
+            return non_canonical("expected integer tag 0x02");
+        }
+        if int_len == 0 {
+            return non_canonical("empty integer");
+        }
+        if sig[start] & 0x80 != 0 {
+            return non_canonical("negative integer");
+        }
+        if int_len > 1 && sig[start] == 0x00 && sig[start + 1] & 0x80 == 0 {
+            return non_canonical("unnecessary leading zero byte");
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads a short-form DER length at `pos`. Signatures never need the long form,
+/// so any length byte `>= 0x80` is treated as an over-long encoding.
+fn der_length(bytes: &[u8], pos: usize) -> Result<usize, SignatureError> {
+    match bytes.get(pos) {
+        Some(len) if *len < 0x80 => Ok(*len as usize),
+        Some(len) => Err(SignatureError::InvalidDer(format!(
+            "long-form length 0x{:02x} not allowed",
+            len
+        ))),
+        None => Err(SignatureError::InvalidDer("missing length".to_string())),
+    }
+}
+
+/// Parses a DER INTEGER at `pos`, returning the value and the position after it.
+fn parse_der_integer(bytes: &[u8], pos: usize) -> Result<(BigInt, usize), SignatureError> {
+    if bytes.get(pos) != Some(&0x02) {
+        return Err(SignatureError::InvalidDer(
+            "expected integer tag 0x02".to_string(),
+        ));
+    }
+    let len = der_length(bytes, pos + 1)?;
+    let start = pos + 2;
+    let value = bytes
+        .get(start..start + len)
+        .ok_or_else(|| SignatureError::InvalidDer("integer overruns input".to_string()))?;
+
+    if value.is_empty() {
+        return Err(SignatureError::InvalidDer("empty integer".to_string()));
+    }
+    if value[0] & 0x80 != 0 {
+        return Err(SignatureError::InvalidDer("negative integer".to_string()));
+    }
+
+    Ok((BigInt::from_bytes_be(Sign::Plus, value), start + len))
+}
+
+/// Message hash `z` for [`PrivateKey::sign`](crate::ecc::private_key::PrivateKey::sign) and
+/// [`S256Point::verify`]: `hash256(msg)` read as a big-endian integer. All 256 bits are kept;
+/// since `N` is close to `2^256` no truncation to the bit length of the order is needed.
+pub fn hash_to_scalar(msg: &[u8]) -> BigInt {
+    BigInt::from_bytes_be(Sign::Plus, &hash256(msg))
+}
+
+/// Message hash `z` for a legacy signature: `hash256` of the serialized sighash preimage (the
+/// modified transaction with the 4-byte sighash type appended), read as a big-endian integer.
+pub fn sighash(preimage: &[u8]) -> BigInt {
+    hash_to_scalar(preimage)
+}
+
+/// [`sighash`] of a UTF-8 message, for signing and verifying test strings.
+pub fn sighash_str(msg: &str) -> BigInt {
+    sighash(msg.as_bytes())
+}
+
+/// Signature plus the 2-bit recovery id: bit 0 is the parity of `R.y`, bit 1 is set
+/// when `R.x` was `>= N` and wrapped around when reduced to `r`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecoverableSignature {
+    pub sig: Signature,
+    pub recovery_id: u8,
+}
+
+/// Reconstructs the signer's public key `Q = r^-1 * (s*R - z*G)` from a signature over
+/// message hash `z`.
+pub fn recover_public_key(
+    z: &BigInt,
+    rsig: &RecoverableSignature,
+) -> Result<S256Point, SignatureError> {
+    let Signature { r, s } = &rsig.sig;
+    if rsig.recovery_id > 3 {
+        return Err(SignatureError::RecoveryFailed(format!(
+            "recovery id {} is not in 0..=3",
+            rsig.recovery_id
+        )));
+    }
+    let n = S256Point::order();
+    let in_range = |v: &BigInt| v.is_positive() && v < n;
+    if !in_range(r) || !in_range(s) {
+        return Err(SignatureError::ScalarOutOfRange(format!(
+            "r and s must be in [1, {:x})",
+            n
+        )));
+    }
+
+    let recovery_failed = |err| SignatureError::RecoveryFailed(format!("{}", err));
+
+    let big_r = lift_r(r, rsig.recovery_id).map_err(recovery_failed)?;
+
+    let r_inv = Scalar::new(r.clone())
+        .inverse_mod_n()
+        .map_err(recovery_failed)?;
+    let u = Scalar::from(0u8)
+        .sub_mod_n(&Scalar::new(z.clone()))
+        .mul_mod_n(&r_inv);
+    let v = Scalar::new(s.clone()).mul_mod_n(&r_inv);
+
+    let u_g = (u * &S256Point::generator()).map_err(recovery_failed)?;
+    let v_r = (v * &big_r).map_err(recovery_failed)?;
+    let q = (u_g + v_r).map_err(recovery_failed)?;
+    if q.x().is_none() {
+        return Err(SignatureError::RecoveryFailed(
+            "recovered point is infinity".to_string(),
+        ));
+    }
+    Ok(q)
+}
+
+/// Signatures per chunk when `batch_verify_recoverable` runs on several threads.
+#[cfg(feature = "rayon")]
+const BATCH_CHUNK: usize = 64;
+
+/// Verifies a batch of `(z, signature, public key)` triples, returning `true` only when every
+/// signature is valid. A single invalid signature fails the whole batch, without saying which
+/// one; fall back to [`S256Point::verify`] to find it.
+///
+/// A plain signature only fixes `R.x`, and the random linear combination in
+/// [`batch_verify_recoverable`] needs `R` itself: with either lift of `R.x` the combination
+/// only balances when every guessed parity is right, and telling `R` from `-R` costs as much
+/// as verifying the signature. So each signature is checked on its own, which accepts either
+/// parity. With the `rayon` feature the checks run on separate threads. Signatures that come
+/// with recovery ids should go through [`batch_verify_recoverable`] instead.
+pub fn batch_verify(items: &[(BigInt, Signature, S256Point)]) -> bool {
+    let valid = |(z, sig, point): &(BigInt, Signature, S256Point)| {
+        point.x().is_some() && point.verify(z, sig).unwrap_or(false)
+    };
+
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+
+        items.par_iter().all(valid)
+    }
+    #[cfg(not(feature = "rayon"))]
+    items.iter().all(valid)
+}
+
+/// [`batch_verify`] for signatures with recovery ids, folding the whole batch into a single
+/// multi-scalar multiplication.
+///
+/// Each valid signature satisfies `u_i*G + v_i*P_i - R_i = 0`. The checks are folded into one
+/// random linear combination `(sum a_i*u_i)*G + sum a_i*v_i*P_i - sum a_i*R_i = 0`, evaluated
+/// as a single multi-scalar multiplication that shares its doublings across all terms. Plain
+/// ECDSA signatures only fix `R.x`, so the recovery id is needed to pin down `R`. The weights
+/// `a_i` are derived by hashing the whole batch, so they can't be chosen ahead of time by
+/// whoever produced the signatures.
+///
+/// With the `rayon` feature the batch is split into chunks whose combinations are checked on
+/// separate threads. The weights still come from the whole batch, and the result is `true`
+/// only if every chunk passes, so it doesn't depend on how the chunks are scheduled.
+pub fn batch_verify_recoverable(items: &[(BigInt, RecoverableSignature, S256Point)]) -> bool {
+    let seed = batch_seed(items);
+
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+
+        items
+            .par_chunks(BATCH_CHUNK)
+            .enumerate()
+            .all(|(c, chunk)| verify_chunk(chunk, &seed, c * BATCH_CHUNK))
+    }
+    #[cfg(not(feature = "rayon"))]
+    verify_chunk(items, &seed, 0)
+}
+
+/// Checks the random linear combination of `chunk`, whose first item is item `offset` of the
+/// batch `seed` was derived from.
+fn verify_chunk(
+    chunk: &[(BigInt, RecoverableSignature, S256Point)],
+    seed: &[u8; 32],
+    offset: usize,
+) -> bool {
+    let n = S256Point::order();
+
+    let mut g_coef = Scalar::from(0u8);
+    let mut terms = Vec::with_capacity(2 * chunk.len() + 1);
+    for (i, (z, rsig, point)) in chunk.iter().enumerate() {
+        let Signature { r, s } = &rsig.sig;
+        if rsig.recovery_id > 3 || r.is_zero() || s.is_zero() || r >= n || s >= n {
+            return false;
+        }
+        if point.x().is_none() {
+            return false;
+        }
+        let Ok(big_r) = lift_r(r, rsig.recovery_id) else {
+            return false;
+        };
+        let Ok(s_inv) = Scalar::new(s.clone()).inverse_mod_n() else {
+            return false;
+        };
+
+        let weight = batch_weight(seed, offset + i);
+        let u = Scalar::new(z.clone()).mul_mod_n(&s_inv);
+        let v = Scalar::new(r.clone()).mul_mod_n(&s_inv);
+
+        g_coef = g_coef.add_mod_n(&weight.mul_mod_n(&u));
+        terms.push((weight.mul_mod_n(&v), point.clone()));
+        terms.push((Scalar::from(0u8).sub_mod_n(&weight), big_r));
+    }
+    terms.push((g_coef, S256Point::generator()));
+
+    match multi_scalar_mul(&terms) {
+        Ok(total) => total.x().is_none(),
+        Err(_) => false,
+    }
+}
+
+/// `hash256` over every `(z, r, s, recovery id, SEC public key)` in the batch.
+fn batch_seed(items: &[(BigInt, RecoverableSignature, S256Point)]) -> [u8; 32] {
+    let mut data = Vec::with_capacity(items.len() * 130);
+    for (z, rsig, point) in items {
+        data.extend(Scalar::reduce_mod_n(z.clone()).to_bytes());
+        data.extend(Scalar::reduce_mod_n(rsig.sig.r.clone()).to_bytes());
+        data.extend(Scalar::reduce_mod_n(rsig.sig.s.clone()).to_bytes());
+        data.push(rsig.recovery_id);
+        if point.x().is_some() {
+            data.extend(point.to_sec_compressed());
+        }
+    }
+    hash256(&data)
+}
+
+/// Weight `a_i = hash256(seed || i) mod N`, with a zero weight bumped to one so every item
+/// stays in the combination.
+fn batch_weight(seed: &[u8; 32], i: usize) -> Scalar {
+    let weight = Scalar::reduce_mod_n(hash_to_scalar(
+        &[&seed[..], &(i as u64).to_be_bytes()].concat(),
+    ));
+    if weight.n.is_zero() {
+        Scalar::from(1u8)
+    } else {
+        weight
+    }
+}
+
+/// `sum k_i * P_i`, walking all scalars' bits together so the doublings are shared.
+pub(crate) fn multi_scalar_mul(
+    terms: &[(Scalar, S256Point)],
+) -> Result<S256Point, FieldElementError> {
+    let bits = terms.iter().map(|(k, _)| k.n.bits()).max().unwrap_or(0);
+    let mut result = S256Point::new(None, None)?;
+    for i in (0..bits).rev() {
+        result = (&result + &result)?;
+        for (k, point) in terms {
+            if k.n.bit(i) {
+                result.try_add_assign(point)?;
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Nonce point `R` from `r` and a recovery id: `R.x` is `r`, or `r + N` when bit 1 is set,
+/// and bit 0 gives the parity of `R.y`.
+fn lift_r(r: &BigInt, recovery_id: u8) -> Result<S256Point, FieldElementError> {
+    let x = if recovery_id & 2 != 0 {
+        r + S256Point::order()
+    } else {
+        r.clone()
+    };
+    let x = S256Field::new(x)?;
+    let mut sec = [0u8; 33];
+    sec[0] = 0x02 | (recovery_id & 1);
+    sec[1..].copy_from_slice(&x.to_bytes());
+    S256Point::parse(&sec)
+}
+
+/// Encodes an unsigned integer as a minimal DER INTEGER, prepending `0x00`
+/// when the high bit is set so it isn't read back as negative.
+fn der_integer(value: &BigInt) -> Vec<u8> {
+    let (_, mut bytes) = value.to_bytes_be();
+    if bytes[0] & 0x80 != 0 {
+        bytes.insert(0, 0x00);
+    }
+
+    let mut out = vec![0x02, bytes.len() as u8];
+    out.extend(bytes);
+    out
+}
+
+impl Display for Signature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Signature({:x},{:x})", self.r, self.s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_traits::Num;
+
+    use super::*;
+    use crate::ecc::private_key::PrivateKey;
+    use crate::encoding::hex;
+
+    fn from_hex(input: &str) -> BigInt {
+        BigInt::from_str_radix(input, 16).unwrap()
+    }
+
+    fn hex_bytes(input: &str) -> Vec<u8> {
+        hex::decode(input).unwrap()
+    }
+
+    #[test]
+    fn to_der() {
+        let sig = Signature::new_unchecked(
+            from_hex("37206a0610995c58074999cb9767b87af4c4978db68c06e8e6e81d282047a7c6"),
+            from_hex("8ca63759c1157ebeaec0d03cecca119fc9a75bf8e6d0fa65c841c8e2738cdaec"),
+        );
+        assert_eq!(
+            sig.to_der(),
+            hex_bytes(
+                "3045022037206a0610995c58074999cb9767b87af4c4978db68c06e8e6e81d282047a7c6\
+                 0221008ca63759c1157ebeaec0d03cecca119fc9a75bf8e6d0fa65c841c8e2738cdaec"
+            )
+        );
+    }
+
+    #[test]
+    fn compact_round_trip() {
+        let sig = Signature::new_unchecked(
+            from_hex("37206a0610995c58074999cb9767b87af4c4978db68c06e8e6e81d282047a7c6"),
+            from_hex("8ca63759c1157ebeaec0d03cecca119fc9a75bf8e6d0fa65c841c8e2738cdaec"),
+        );
+        let compact = sig.to_compact();
+        assert_eq!(
+            compact[..],
+            hex_bytes(
+                "37206a0610995c58074999cb9767b87af4c4978db68c06e8e6e81d282047a7c6\
+                 8ca63759c1157ebeaec0d03cecca119fc9a75bf8e6d0fa65c841c8e2738cdaec"
+            )[..]
+        );
+        assert_eq!(Signature::from_compact(&compact).unwrap(), sig);
+
+        // small values are left-padded
+        let small = Signature::new_unchecked(BigInt::from(1u8), BigInt::from(2u8));
+        let compact = small.to_compact();
+        assert_eq!((compact[31], compact[63]), (1, 2));
+        assert_eq!(Signature::from_compact(&compact).unwrap(), small);
+    }
+
+    #[test]
+    fn sighash_of_known_preimage() {
+        // SIGHASH_ALL preimage for input 0 of mainnet transaction
+        // 452c629d67e41baec3ac6f04fe744b4b9617f8f859c63b3002f8684e7a4fee03
+        let preimage = hex::decode(concat!(
+            "0100000001813f79011acb80925dfe69b3def355fe914bd1d96a3f5f71bf8303c6a989c7d1",
+            "000000001976a914a802fc56c704ce87c42d7c92eb75e7896bdc41ae88acfeffffff02a135",
+            "ef01000000001976a914bc3b654dca7e56b04dca18f2566cdaf02e8d9ada88ac99c3980000",
+            "0000001976a9141c4bc762dd5423e332166702cb75f40df79fea1288ac1943060001000000"
+        ))
+        .unwrap();
+        let z = sighash(&preimage);
+        assert_eq!(
+            z,
+            BigInt::from_str_radix(
+                "27e0c5994dec7824e56dec6b2fcb342eb7cdb0d0957c2fce9882f715e85d81a6",
+                16
+            )
+            .unwrap()
+        );
+
+        let sec = "0349fc4e631e3624a545de3f89f5d8684c7b8138bd94bdd531d2e213bf016b278a";
+        let der = concat!(
+            "3045022100ed81ff192e75a3fd2304004dcadb746fa5e24c5031ccfcf21320b0277457c98f",
+            "02207a986d955c6e0cb35d446a89d3f56100f4d7f67801c31967743a9c8e10615bed"
+        );
+        let point = S256Point::parse(&hex::decode(sec).unwrap()).unwrap();
+        let sig = Signature::from_der(&hex::decode(der).unwrap()).unwrap();
+        assert!(point.verify(&z, &sig).unwrap());
+    }
+
+    #[test]
+    fn sighash_str_hashes_utf8() {
+        assert_eq!(
+            sighash_str("Programming Bitcoin!"),
+            sighash(b"Programming Bitcoin!")
+        );
+        assert_eq!(
+            sighash_str(""),
+            BigInt::from_bytes_be(Sign::Plus, &hash256(b""))
+        );
+    }
+
+    #[test]
+    fn new_rejects_out_of_range() {
+        let n = S256Point::order().clone();
+        let one = BigInt::from(1u8);
+
+        assert!(Signature::new(one.clone(), &n - 1u8).is_ok());
+        assert!(matches!(
+            Signature::new(BigInt::zero(), one.clone()),
+            Err(SignatureError::ZeroScalar(_))
+        ));
+        assert!(matches!(
+            Signature::new(one.clone(), BigInt::zero()),
+            Err(SignatureError::ZeroScalar(_))
+        ));
+        assert!(matches!(
+            Signature::new(one.clone(), n.clone()),
+            Err(SignatureError::ScalarOutOfRange(_))
+        ));
+        assert!(matches!(
+            Signature::new(n, one.clone()),
+            Err(SignatureError::ScalarOutOfRange(_))
+        ));
+        assert!(matches!(
+            Signature::new(-one.clone(), one),
+            Err(SignatureError::ScalarOutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn from_compact_rejects_out_of_range() {
+        let r = from_hex("37206a0610995c58074999cb9767b87af4c4978db68c06e8e6e81d282047a7c6");
+
+        let high_s = Signature::new_unchecked(r.clone(), S256Point::order().clone()).to_compact();
+        assert!(matches!(
+            Signature::from_compact(&high_s),
+            Err(SignatureError::ScalarOutOfRange(_))
+        ));
+
+        let mut max_s = high_s;
+        max_s[32..].fill(0xff);
+        assert!(matches!(
+            Signature::from_compact(&max_s),
+            Err(SignatureError::ScalarOutOfRange(_))
+        ));
+
+        let zero_s = Signature::new_unchecked(r, BigInt::zero()).to_compact();
+        assert!(matches!(
+            Signature::from_compact(&zero_s),
+            Err(SignatureError::ZeroScalar(_))
+        ));
+    }
+
+    #[test]
+    fn from_der() {
+        let sig = Signature::new_unchecked(
+            from_hex("37206a0610995c58074999cb9767b87af4c4978db68c06e8e6e81d282047a7c6"),
+            from_hex("8ca63759c1157ebeaec0d03cecca119fc9a75bf8e6d0fa65c841c8e2738cdaec"),
+        );
+        assert_eq!(Signature::from_der(&sig.to_der()).unwrap(), sig);
+
+        let small = Signature::new_unchecked(BigInt::from(0x80u8), BigInt::from(1u8));
+        assert_eq!(Signature::from_der(&small.to_der()).unwrap(), small);
+    }
+
+    #[test]
+    fn der_with_sighash_round_trip() {
+        let sig = Signature::new_unchecked(
+            from_hex("37206a0610995c58074999cb9767b87af4c4978db68c06e8e6e81d282047a7c6"),
+            from_hex("8ca63759c1157ebeaec0d03cecca119fc9a75bf8e6d0fa65c841c8e2738cdaec"),
+        );
+        let bytes = sig.to_der_with_sighash(SIGHASH_ALL);
+        assert_eq!(bytes, [sig.to_der(), vec![0x01]].concat());
+        assert_eq!(
+            Signature::from_der_with_sighash(&bytes).unwrap(),
+            (sig.clone(), SIGHASH_ALL)
+        );
+
+        // missing sighash byte, and one byte too many
+        let der = sig.to_der();
+        assert!(matches!(
+            Signature::from_der_with_sighash(&der),
+            Err(SignatureError::InvalidDer(_))
+        ));
+        assert!(matches!(
+            Signature::from_der_with_sighash(&[bytes.as_slice(), &[0x01]].concat()),
+            Err(SignatureError::InvalidDer(_))
+        ));
+        assert!(matches!(
+            Signature::from_der_with_sighash(&[]),
+            Err(SignatureError::InvalidLength(_))
+        ));
+    }
+
+    #[test]
+    fn from_der_rejects_malformed() {
+        let der = Signature::new_unchecked(BigInt::from(0x80u8), BigInt::from(1u8)).to_der();
+        // 30 07 02 02 00 80 02 01 01
+
+        let malformed: Vec<Vec<u8>> = vec![
+            // wrong sequence tag
+            [&[0x31], &der[1..]].concat(),
+            // sequence length too long / too short
+            [&[0x30, 0x08], &der[2..]].concat(),
+            [&[0x30, 0x06], &der[2..]].concat(),
+            // trailing garbage
+            [&der[..], &[0x00]].concat(),
+            // wrong integer tag
+            vec![0x30, 0x07, 0x03, 0x02, 0x00, 0x80, 0x02, 0x01, 0x01],
+            // negative r
+            vec![0x30, 0x06, 0x02, 0x01, 0x80, 0x02, 0x01, 0x01],
+            // empty s
+            vec![0x30, 0x06, 0x02, 0x02, 0x00, 0x80, 0x02, 0x00],
+            // s overruns the sequence
+            vec![0x30, 0x07, 0x02, 0x02, 0x00, 0x80, 0x02, 0x02, 0x01],
+            // long-form length
+            vec![0x30, 0x81, 0x07, 0x02, 0x02, 0x00, 0x80, 0x02, 0x01, 0x01],
+        ];
+
+        for bytes in malformed {
+            assert!(
+                matches!(
+                    Signature::from_der(&bytes),
+                    Err(SignatureError::InvalidDer(_))
+                ),
+                "{:02x?} should not parse",
+                bytes
+            );
+        }
+    }
+
+    #[test]
+    fn from_der_strict() {
+        let sig = Signature::new_unchecked(
+            from_hex("37206a0610995c58074999cb9767b87af4c4978db68c06e8e6e81d282047a7c6"),
+            from_hex("3a4b7f9b06a87d18abc9d5e8a6b3e9d2c4f6e0a4b2c8d1e3f5a7b9c0d2e4f6a8"),
+        );
+        let der = sig.to_der();
+        assert_eq!(Signature::from_der_strict(&der).unwrap(), sig);
+
+        let small = Signature::new_unchecked(BigInt::from(0x80u8), BigInt::from(1u8)).to_der();
+        // 30 07 02 02 00 80 02 01 01
+        assert!(Signature::from_der_strict(&small).is_ok());
+
+        let malleated: Vec<Vec<u8>> = vec![
+            // r padded with a zero byte it doesn't need; from_der accepts this
+            vec![0x30, 0x07, 0x02, 0x02, 0x00, 0x01, 0x02, 0x01, 0x01],
+            // s padded the same way
+            vec![0x30, 0x08, 0x02, 0x02, 0x00, 0x80, 0x02, 0x02, 0x00, 0x01],
+            // r padded twice even though its high bit needs one zero
+            vec![0x30, 0x08, 0x02, 0x03, 0x00, 0x00, 0x80, 0x02, 0x01, 0x01],
+            // negative r
+            vec![0x30, 0x06, 0x02, 0x01, 0x80, 0x02, 0x01, 0x01],
+            // sequence length off by one
+            [&[0x30, 0x08], &small[2..]].concat(),
+            // trailing byte
+            [&small[..], &[0x01]].concat(),
+            // high s: N - s verifies just the same
+            Signature::new_unchecked(sig.r.clone(), S256Point::order() - &sig.s).to_der(),
+        ];
+
+        assert!(Signature::from_der(&malleated[0]).is_ok());
+        for bytes in malleated {
+            assert!(
+                matches!(
+                    Signature::from_der_strict(&bytes),
+                    Err(SignatureError::NonCanonical(_))
+                ),
+                "{:02x?} should be rejected",
+                bytes
+            );
+        }
+
+        // s + N is low once reduced but is not the canonical value
+        let wrapped = Signature::new_unchecked(sig.r.clone(), S256Point::order() + &sig.s);
+        assert!(matches!(
+            Signature::from_der_strict(&wrapped.to_der()),
+            Err(SignatureError::ScalarOutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn from_der_rejects_bad_length() {
+        let der = Signature::new_unchecked(BigInt::from(1u8), BigInt::from(1u8)).to_der();
+        for bytes in [vec![], vec![0x30], der[..7].to_vec(), vec![0x30; 73]] {
+            assert!(matches!(
+                Signature::from_der(&bytes),
+                Err(SignatureError::InvalidLength(_))
+            ));
+        }
+        assert!(Signature::from_der(&der).is_ok());
+    }
+
+    #[test]
+    fn from_der_rejects_zero_scalars() {
+        for (r, s) in [(0u8, 1u8), (1, 0), (0, 0)] {
+            let der = Signature::new_unchecked(BigInt::from(r), BigInt::from(s)).to_der();
+            assert!(matches!(
+                Signature::from_der(&der),
+                Err(SignatureError::ZeroScalar(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn from_der_rejects_out_of_range_scalars() {
+        let n = S256Point::order();
+        let oversized = BigInt::from(1u8) << 318;
+        for (r, s) in [
+            (n.clone(), BigInt::from(1u8)),
+            (BigInt::from(1u8), n + 1u8),
+            // 40-byte r, still well-formed DER
+            (oversized, BigInt::from(1u8)),
+        ] {
+            let der = Signature::new_unchecked(r, s).to_der();
+            assert!(matches!(
+                Signature::from_der(&der),
+                Err(SignatureError::ScalarOutOfRange(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn ensure_low_s() {
+        let half_n: BigInt = S256Point::order() >> 1;
+        let low = Signature::new_unchecked(BigInt::from(1u8), half_n.clone());
+        assert!(low.ensure_low_s().is_ok());
+
+        let high = Signature::new_unchecked(BigInt::from(1u8), half_n + 1);
+        assert!(matches!(high.ensure_low_s(), Err(SignatureError::HighS(_))));
+    }
+
+    #[test]
+    fn normalize_s() {
+        let point = S256Point::parse(&hex_bytes(
+            "04887387e452b8eacc4acfde10d9aaf7f6d9a0f975aabb10d006e4da568744d06c\
+             61de6d95231cd89026e286df3b6ae4a894a3378e393e93a0f45b666329a0ae34",
+        ))
+        .unwrap();
+        let z = from_hex("7c076ff316692a3d7eb3c3bb0f8b1488cf72e1afcd929e29307032997a838a3d");
+        let high = Signature::new_unchecked(
+            from_hex("eff69ef2b1bd93a66ed5219add4fb51e11a840f404876325a1e8ffe0529a2c"),
+            from_hex("c7207fee197d27c618aea621406f6bf5ef6fca38681d82b2f06fddbdce6feab6"),
+        );
+        assert!(!high.is_low_s());
+        assert!(point.verify(&z, &high).unwrap());
+
+        let mut low = high.clone();
+        low.normalize_s();
+        assert!(low.is_low_s());
+        assert_eq!(low.r, high.r);
+        assert_eq!(low.s, S256Point::order() - &high.s);
+        assert!(point.verify(&z, &low).unwrap());
+
+        let normalized = low.clone();
+        low.normalize_s();
+        assert_eq!(low, normalized);
+    }
+
+    #[test]
+    fn recover_public_key_round_trip() {
+        for (secret, msg) in [(1u32, "Satoshi Nakamoto"), (12345, "rbtc"), (987654321, "")] {
+            let key = PrivateKey::new(Scalar::from(secret)).unwrap();
+            let z = hash_to_scalar(msg.as_bytes());
+
+            let rsig = key.sign_recoverable(&z).unwrap();
+            assert_eq!(rsig.sig, key.sign(&z).unwrap());
+            assert_eq!(
+                recover_public_key(&z, &rsig).unwrap(),
+                *key.public_key().unwrap()
+            );
+
+            let wrong_parity = RecoverableSignature {
+                sig: rsig.sig.clone(),
+                recovery_id: rsig.recovery_id ^ 1,
+            };
+            assert_ne!(
+                recover_public_key(&z, &wrong_parity).unwrap(),
+                *key.public_key().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn batch_verify() {
+        let mut items = Vec::new();
+        for (secret, msg) in [(1u32, "a"), (12345, "b"), (987654321, "c"), (42, "d")] {
+            let key = PrivateKey::new(Scalar::from(secret)).unwrap();
+            let z = hash_to_scalar(msg.as_bytes());
+            // plain signatures, as parsed from DER, with no recovery id to go on
+            let der = key.sign(&z).unwrap().to_der();
+            items.push((
+                z,
+                Signature::from_der(&der).unwrap(),
+                key.public_key().unwrap().clone(),
+            ));
+        }
+        assert!(super::batch_verify(&items));
+        assert!(super::batch_verify(&[]));
+
+        // one tampered message hash fails the whole batch
+        let mut tampered = items.clone();
+        tampered[2].0 += 1;
+        assert!(!super::batch_verify(&tampered));
+
+        // so does a signature checked against the wrong key
+        let mut swapped = items;
+        swapped[0].2 = swapped[1].2.clone();
+        assert!(!super::batch_verify(&swapped));
+    }
+
+    #[test]
+    fn batch_verify_recoverable() {
+        let mut items = Vec::new();
+        for (secret, msg) in [(1u32, "a"), (12345, "b"), (987654321, "c"), (42, "d")] {
+            let key = PrivateKey::new(Scalar::from(secret)).unwrap();
+            let z = hash_to_scalar(msg.as_bytes());
+            items.push((
+                z.clone(),
+                key.sign_recoverable(&z).unwrap(),
+                key.public_key().unwrap().clone(),
+            ));
+        }
+        assert!(super::batch_verify_recoverable(&items));
+        assert!(super::batch_verify_recoverable(&[]));
+
+        // one tampered message hash fails the whole batch
+        let mut tampered = items.clone();
+        tampered[2].0 += 1;
+        assert!(!super::batch_verify_recoverable(&tampered));
+
+        // so does a signature checked against the wrong key
+        let mut swapped = items.clone();
+        swapped[0].2 = items[1].2.clone();
+        assert!(!super::batch_verify_recoverable(&swapped));
+
+        // and a recovery id with the wrong parity
+        let mut wrong_parity = items;
+        wrong_parity[3].1.recovery_id ^= 1;
+        assert!(!super::batch_verify_recoverable(&wrong_parity));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel_batch_verify_recoverable_matches_serial() {
+        let keys: Vec<PrivateKey> = (1u32..=4)
+            .map(|i| PrivateKey::new(Scalar::from(i * 7919)).unwrap())
+            .collect();
+        let mut items = Vec::new();
+        for i in 0..2 * BATCH_CHUNK + 5 {
+            let key = &keys[i % keys.len()];
+            let z = hash_to_scalar(&(i as u64).to_be_bytes());
+            items.push((
+                z.clone(),
+                key.sign_recoverable(&z).unwrap(),
+                key.public_key().unwrap().clone(),
+            ));
+        }
+        let serial = |items: &[(BigInt, RecoverableSignature, S256Point)]| {
+            verify_chunk(items, &batch_seed(items), 0)
+        };
+
+        assert!(serial(&items));
+        assert!(super::batch_verify_recoverable(&items));
+
+        // a bad signature in the last, partial chunk fails both paths
+        let mut tampered = items;
+        tampered[2 * BATCH_CHUNK + 3].0 += 1;
+        assert!(!serial(&tampered));
+        assert!(!super::batch_verify_recoverable(&tampered));
+    }
+
+    #[test]
+    fn recover_public_key_rejects_bad_recovery_id() {
+        let key = PrivateKey::new(Scalar::from(7u8)).unwrap();
+        let z = BigInt::from(42u8);
+        let mut rsig = key.sign_recoverable(&z).unwrap();
+        rsig.recovery_id = 4;
+        assert!(matches!(
+            recover_public_key(&z, &rsig),
+            Err(SignatureError::RecoveryFailed(_))
+        ));
+    }
+
+    #[test]
+    fn recover_public_key_rejects_invalid_scalars() {
+        let z = BigInt::from(42u8);
+        let n = S256Point::order();
+        for (r, s) in [
+            (BigInt::zero(), BigInt::from(1u8)),
+            (n.clone(), BigInt::from(1u8)),
+            (BigInt::from(1u8), BigInt::zero()),
+            (BigInt::from(1u8), n.clone()),
+            (BigInt::from(1u8), -BigInt::from(1u8)),
+        ] {
+            let rsig = RecoverableSignature {
+                sig: Signature::new_unchecked(r, s),
+                recovery_id: 0,
+            };
+            assert!(matches!(
+                recover_public_key(&z, &rsig),
+                Err(SignatureError::ScalarOutOfRange(_))
+            ));
+        }
+
+        // x = 5 has no point on the curve since 5^3 + 7 is not a square mod p
+        let off_curve = RecoverableSignature {
+            sig: Signature::new_unchecked(BigInt::from(5u8), BigInt::from(1u8)),
+            recovery_id: 0,
+        };
+        assert!(matches!(
+            recover_public_key(&z, &off_curve),
+            Err(SignatureError::RecoveryFailed(_))
+        ));
+    }
+
+    #[test]
+    fn hash_to_scalar_is_hash256() {
+        assert_eq!(
+            hash_to_scalar(b"my message"),
+            from_hex("0231c6f3d980a6b0fb7152f85cee7eb52bf92433d9919b9c5218cb08e79cce78")
+        );
+
+        let key = PrivateKey::new(Scalar::from(12345u32)).unwrap();
+        let z = hash_to_scalar(b"Programming Bitcoin!");
+        let sig = key.sign(&z).unwrap();
+        assert!(key.public_key().unwrap().verify(&z, &sig).unwrap());
+    }
+
+    #[test]
+    fn to_der_minimal_integers() {
+        let sig = Signature::new_unchecked(BigInt::from(1u8), BigInt::from(0x7fu8));
+        assert_eq!(
+            sig.to_der(),
+            vec![0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x7f]
+        );
+
+        let sig = Signature::new_unchecked(BigInt::from(0x80u8), BigInt::from(0x0100u16));
+        assert_eq!(
+            sig.to_der(),
+            vec![0x30, 0x08, 0x02, 0x02, 0x00, 0x80, 0x02, 0x02, 0x01, 0x00]
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use alloc::format;
+
+use crate::encoding::base58::base58check_decode;
+use crate::encoding::bech32::decode_segwit;
+use crate::encoding::error::EncodingError;
+
+/// A decoded Bitcoin address: the kind of output it pays to and its hash or key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Address {
+    P2PKH([u8; 20]),
+    P2SH([u8; 20]),
+    P2WPKH([u8; 20]),
+    P2TR([u8; 32]),
+}
+
+impl Address {
+    /// Decodes a base58check (P2PKH, P2SH) or bech32/bech32m (P2WPKH, P2TR) address,
+    /// checking its checksum and that its version byte or hrp belongs to the requested
+    /// network.
+    pub fn decode(s: &str, testnet: bool) -> Result<Address, EncodingError> {
+        let lower = s.to_ascii_lowercase();
+        if lower.starts_with("bc1") || lower.starts_with("tb1") {
+            Address::decode_segwit(s, &lower[..2], testnet)
+        } else {
+            Address::decode_base58(s, testnet)
+        }
+    }
+
+    fn decode_base58(s: &str, testnet: bool) -> Result<Address, EncodingError> {
+        let payload = base58check_decode(s)?;
+        if payload.len() != 21 {
+            return Err(EncodingError::InvalidLength(format!(
+                "base58 address payload is {} bytes, expected 21",
+                payload.len()
+            )));
+        }
+
+        let mut hash = [0u8; 20];
+        hash.copy_from_slice(&payload[1..]);
+        match (payload[0], testnet) {
+            (0x00, false) | (0x6f, true) => Ok(Address::P2PKH(hash)),
+            (0x05, false) | (0xc4, true) => Ok(Address::P2SH(hash)),
+            (version, _) => Err(EncodingError::InvalidVersion(format!(
+                "version byte 0x{:02x} is not a {} address",
+                version,
+                network(testnet)
+            ))),
+        }
+    }
+
+    fn decode_segwit(s: &str, hrp: &str, testnet: bool) -> Result<Address, EncodingError> {
+        let expected = if testnet { "tb" } else { "bc" };
+        if hrp != expected {
+            return Err(EncodingError::InvalidVersion(format!(
+                "hrp {} is not a {} address",
+                hrp,
+                network(testnet)
+            )));
+        }
+
+        match decode_segwit(expected, s)? {
+            (0, program) if program.len() == 20 => Ok(Address::P2WPKH(program.try_into().unwrap())),
+            (1, program) if program.len() == 32 => Ok(Address::P2TR(program.try_into().unwrap())),
+            (version, program) => Err(EncodingError::InvalidPayload(format!(
+                "unsupported witness version {} with a {}-byte program",
+                version,
+                program.len()
+            ))),
+        }
+    }
+}
+
+fn network(testnet: bool) -> &'static str {
+    if testnet {
+        "testnet"
+    } else {
+        "mainnet"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecc::point::s256_point::S256Point;
+    use crate::encoding::hex;
+
+    fn bytes<const N: usize>(input: &str) -> [u8; N] {
+        hex::decode(input).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn decode_each_type() {
+        let vectors = [
+            (
+                "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2",
+                false,
+                Address::P2PKH(bytes("77bff20c60e522dfaa3350c39b030a5d004e839a")),
+            ),
+            (
+                "mmTPbXQFxboEtNRkwfh6K51jvdtHLxGeMA",
+                true,
+                Address::P2PKH(bytes("41243614aecd13819d7a7f348a4a07fbcb29d8e5")),
+            ),
+            (
+                "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy",
+                false,
+                Address::P2SH(bytes("b472a266d0bd89c13706a4132ccfb16f7c3b9fcb")),
+            ),
+            (
+                "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+                false,
+                Address::P2WPKH(bytes("751e76e8199196d454941c45d1b3a323f1433bd6")),
+            ),
+            (
+                "BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4",
+                false,
+                Address::P2WPKH(bytes("751e76e8199196d454941c45d1b3a323f1433bd6")),
+            ),
+            (
+                "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0",
+                false,
+                Address::P2TR(S256Point::generator().to_x_only()),
+            ),
+        ];
+
+        for (address, testnet, expected) in vectors {
+            assert_eq!(Address::decode(address, testnet).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn decode_rejects_other_network() {
+        for (address, testnet) in [
+            ("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2", true),
+            ("mmTPbXQFxboEtNRkwfh6K51jvdtHLxGeMA", false),
+            ("3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy", true),
+            ("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4", true),
+            ("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx", false),
+        ] {
+            assert!(
+                matches!(
+                    Address::decode(address, testnet),
+                    Err(EncodingError::InvalidVersion(_))
+                ),
+                "{} should not decode with testnet = {}",
+                address,
+                testnet
+            );
+        }
+    }
+
+    #[test]
+    fn decode_rejects_bad_checksum_and_unsupported_programs() {
+        assert!(matches!(
+            Address::decode("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3", false),
+            Err(EncodingError::ChecksumMismatch(_))
+        ));
+        assert!(Address::decode("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5", false).is_err());
+
+        // P2WSH has a 32-byte v0 program, which this enum doesn't cover
+        assert!(matches!(
+            Address::decode(
+                "bc1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3qccfmv3",
+                false
+            ),
+            Err(EncodingError::InvalidPayload(_))
+        ));
+    }
+}
@@ -0,0 +1,150 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+use num_bigint::BigUint;
+use num_traits::{ToPrimitive, Zero};
+
+use crate::encoding::error::EncodingError;
+use crate::hash::hash256;
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Base58 with the Bitcoin alphabet. Every leading zero byte becomes a leading `1`.
+pub fn encode(bytes: &[u8]) -> String {
+    let zeros = bytes.iter().take_while(|b| **b == 0).count();
+    let mut num = BigUint::from_bytes_be(bytes);
+
+    let mut digits = Vec::new();
+    while !num.is_zero() {
+        let rem = (&num % 58u8).to_usize().unwrap();
+        digits.push(ALPHABET[rem]);
+        num /= 58u8;
+    }
+
+    let mut out = "1".repeat(zeros);
+    out.extend(digits.iter().rev().map(|d| *d as char));
+    out
+}
+
+pub fn decode(s: &str) -> Result<Vec<u8>, EncodingError> {
+    let zeros = s.chars().take_while(|c| *c == '1').count();
+
+    let mut num = BigUint::zero();
+    for c in s.chars() {
+        let digit = ALPHABET
+            .iter()
+            .position(|a| *a as char == c)
+            .ok_or_else(|| EncodingError::InvalidCharacter(format!("{:?} is not base58", c)))?;
+        num = num * 58u8 + digit;
+    }
+
+    let mut out = vec![0u8; zeros];
+    if !num.is_zero() {
+        out.extend(num.to_bytes_be());
+    }
+    Ok(out)
+}
+
+/// Base58 of `payload || hash256(payload)[..4]`.
+pub fn base58check_encode(payload: &[u8]) -> String {
+    let mut bytes = payload.to_vec();
+    bytes.extend_from_slice(&hash256(payload)[..4]);
+    encode(&bytes)
+}
+
+/// Decodes a base58check string, verifying and stripping the 4-byte checksum.
+pub fn base58check_decode(s: &str) -> Result<Vec<u8>, EncodingError> {
+    let mut bytes = decode(s)?;
+    if bytes.len() < 4 {
+        return Err(EncodingError::InvalidLength(format!(
+            "{} bytes is too short for a checksum",
+            bytes.len()
+        )));
+    }
+
+    let checksum = bytes.split_off(bytes.len() - 4);
+    if hash256(&bytes)[..4] != checksum[..] {
+        return Err(EncodingError::ChecksumMismatch(format!(
+            "bad checksum for {}",
+            s
+        )));
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(encode(&[]), "");
+        assert_eq!(decode("").unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn all_zeros() {
+        assert_eq!(encode(&[0, 0, 0]), "111");
+        assert_eq!(decode("111").unwrap(), vec![0, 0, 0]);
+    }
+
+    #[test]
+    fn known_vectors() {
+        let vectors: [(&[u8], &str); 3] = [
+            (b"Hello World!", "2NEpo7TZRRrLZSi2U"),
+            (&[0x00, 0x00, 0x28, 0x7f, 0xb4, 0xcd], "11233QC4"),
+            (
+                &[
+                    0x7c, 0x07, 0x6f, 0xf3, 0x16, 0x69, 0x2a, 0x3d, 0x7e, 0xb3, 0xc3, 0xbb, 0x0f,
+                    0x8b, 0x14, 0x88, 0xcf, 0x72, 0xe1, 0xaf, 0xcd, 0x92, 0x9e, 0x29, 0x30, 0x70,
+                    0x32, 0x99, 0x7a, 0x83, 0x8a, 0x3d,
+                ],
+                "9MA8fRQrT4u8Zj8ZRd6MAiiyaxb2Y1CMpvVkHQu5hVM6",
+            ),
+        ];
+
+        for (bytes, encoded) in vectors {
+            assert_eq!(encode(bytes), encoded);
+            assert_eq!(decode(encoded).unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn base58check_round_trip() {
+        let payload = [
+            0x00, 0x51, 0xb5, 0xd7, 0x5a, 0x4f, 0x33, 0x05, 0xb1, 0xb4, 0x1a, 0xf5, 0x14, 0x48,
+            0x0a, 0xa5, 0xbb, 0x5b, 0x65, 0x1c, 0x3d, 0x07,
+        ];
+        let encoded = base58check_encode(&payload);
+        assert_eq!(base58check_decode(&encoded).unwrap(), payload);
+        assert_eq!(
+            base58check_decode(&base58check_encode(&[])).unwrap(),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn base58check_detects_corruption() {
+        let encoded = base58check_encode(b"Programming Bitcoin");
+        let mut chars: Vec<char> = encoded.chars().collect();
+        chars[5] = if chars[5] == 'z' { 'y' } else { 'z' };
+        let corrupted: String = chars.into_iter().collect();
+
+        assert!(matches!(
+            base58check_decode(&corrupted),
+            Err(EncodingError::ChecksumMismatch(_))
+        ));
+        assert!(matches!(
+            base58check_decode("1"),
+            Err(EncodingError::InvalidLength(_))
+        ));
+    }
+
+    #[test]
+    fn decode_rejects_invalid_characters() {
+        for s in ["0", "O", "I", "l", "abc!"] {
+            assert!(matches!(decode(s), Err(EncodingError::InvalidCharacter(_))));
+        }
+    }
+}
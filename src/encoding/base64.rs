@@ -0,0 +1,124 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::encoding::error::EncodingError;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Standard base64 (RFC 4648) with `=` padding.
+pub fn encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+/// Decodes padded base64. Whitespace, missing padding and non-zero trailing bits are
+/// rejected.
+pub fn decode(s: &str) -> Result<Vec<u8>, EncodingError> {
+    let s = s.as_bytes();
+    if !s.len().is_multiple_of(4) {
+        return Err(EncodingError::InvalidLength(format!(
+            "base64 length {} is not a multiple of 4",
+            s.len()
+        )));
+    }
+
+    let mut out = Vec::with_capacity(s.len() / 4 * 3);
+    for (i, chunk) in s.chunks(4).enumerate() {
+        let last = i == s.len() / 4 - 1;
+        let padding = chunk.iter().rev().take_while(|c| **c == b'=').count();
+        if padding > 2 || (padding > 0 && !last) {
+            return Err(EncodingError::InvalidPayload(
+                "misplaced base64 padding".to_string(),
+            ));
+        }
+
+        let mut n = 0u32;
+        for c in &chunk[..4 - padding] {
+            let digit = ALPHABET.iter().position(|a| a == c).ok_or_else(|| {
+                EncodingError::InvalidCharacter(format!("{:?} is not base64", *c as char))
+            })?;
+            n = (n << 6) | digit as u32;
+        }
+        n <<= 6 * padding;
+
+        let bytes = n.to_be_bytes();
+        let len = 3 - padding;
+        if bytes[1 + len..].iter().any(|b| *b != 0) {
+            return Err(EncodingError::InvalidPayload(
+                "non-zero base64 padding bits".to_string(),
+            ));
+        }
+        out.extend_from_slice(&bytes[1..1 + len]);
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rfc4648_vectors() {
+        let vectors = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ];
+        for (raw, encoded) in vectors {
+            assert_eq!(encode(raw.as_bytes()), encoded);
+            assert_eq!(decode(encoded).unwrap(), raw.as_bytes());
+        }
+    }
+
+    #[test]
+    fn round_trip_all_bytes() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        assert_eq!(decode(&encode(&bytes)).unwrap(), bytes);
+    }
+
+    #[test]
+    fn decode_rejects_malformed() {
+        assert!(matches!(
+            decode("Zm9"),
+            Err(EncodingError::InvalidLength(_))
+        ));
+        assert!(matches!(
+            decode("Zm9*"),
+            Err(EncodingError::InvalidCharacter(_))
+        ));
+        assert!(matches!(
+            decode("Zg==Zm9v"),
+            Err(EncodingError::InvalidPayload(_))
+        ));
+        assert!(matches!(
+            decode("Z==="),
+            Err(EncodingError::InvalidPayload(_))
+        ));
+        // "Zh==" carries non-zero bits past the single decoded byte
+        assert!(matches!(
+            decode("Zh=="),
+            Err(EncodingError::InvalidPayload(_))
+        ));
+    }
+}
@@ -0,0 +1,611 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+use crate::encoding::error::EncodingError;
+
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const MAX_LENGTH: usize = 90;
+const CHECKSUM_LENGTH: usize = 6;
+
+/// Checksum flavour: BIP173 bech32 for witness version 0, BIP350 bech32m for version 1
+/// and up. They differ only in the constant the checksum is XORed with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+    Bech32,
+    Bech32m,
+}
+
+impl Variant {
+    fn constant(self) -> u32 {
+        match self {
+            Variant::Bech32 => 1,
+            Variant::Bech32m => 0x2bc830a3,
+        }
+    }
+
+    fn from_constant(c: u32) -> Option<Variant> {
+        match c {
+            1 => Some(Variant::Bech32),
+            0x2bc830a3 => Some(Variant::Bech32m),
+            _ => None,
+        }
+    }
+}
+
+fn polymod(values: impl Iterator<Item = u8>) -> u32 {
+    const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+    let mut chk = 1u32;
+    for v in values {
+        let top = chk >> 25;
+        chk = (chk & 0x1ffffff) << 5 ^ u32::from(v);
+        for (i, g) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= g;
+            }
+        }
+    }
+    chk
+}
+
+/// High bits of each hrp character, a zero separator, then the low bits.
+fn hrp_expand(hrp: &str) -> impl Iterator<Item = u8> + '_ {
+    hrp.bytes()
+        .map(|c| c >> 5)
+        .chain([0])
+        .chain(hrp.bytes().map(|c| c & 31))
+}
+
+fn checksum(hrp: &str, data: &[u8], variant: Variant) -> [u8; CHECKSUM_LENGTH] {
+    let pm = polymod(
+        hrp_expand(hrp)
+            .chain(data.iter().copied())
+            .chain([0; CHECKSUM_LENGTH]),
+    ) ^ variant.constant();
+
+    let mut out = [0u8; CHECKSUM_LENGTH];
+    for (i, c) in out.iter_mut().enumerate() {
+        *c = ((pm >> (5 * (5 - i))) & 31) as u8;
+    }
+    out
+}
+
+fn check_hrp(hrp: &str) -> Result<(), EncodingError> {
+    if hrp.is_empty() || hrp.len() > MAX_LENGTH - 1 - CHECKSUM_LENGTH {
+        return Err(EncodingError::InvalidLength(format!(
+            "hrp length {} is not in 1..=83",
+            hrp.len()
+        )));
+    }
+    if let Some(c) = hrp.bytes().find(|c| !(33..=126).contains(c)) {
+        return Err(EncodingError::InvalidCharacter(format!(
+            "0x{:02x} is not allowed in the hrp",
+            c
+        )));
+    }
+    Ok(())
+}
+
+/// Bech32 (BIP173) encoding of `hrp` and `data`, a sequence of 5-bit values. The hrp is
+/// lowercased.
+pub fn encode(hrp: &str, data: &[u8]) -> Result<String, EncodingError> {
+    encode_variant(hrp, data, Variant::Bech32)
+}
+
+/// Like [`encode`], with the checksum of the given variant.
+pub fn encode_variant(hrp: &str, data: &[u8], variant: Variant) -> Result<String, EncodingError> {
+    check_hrp(hrp)?;
+    if let Some(v) = data.iter().find(|v| **v > 31) {
+        return Err(EncodingError::InvalidPayload(format!(
+            "{} is not a 5-bit value",
+            v
+        )));
+    }
+    let len = hrp.len() + 1 + data.len() + CHECKSUM_LENGTH;
+    if len > MAX_LENGTH {
+        return Err(EncodingError::InvalidLength(format!(
+            "{} characters exceeds the limit of {}",
+            len, MAX_LENGTH
+        )));
+    }
+
+    let hrp = hrp.to_ascii_lowercase();
+    let mut out = format!("{}1", hrp);
+    for v in data.iter().chain(checksum(&hrp, data, variant).iter()) {
+        out.push(CHARSET[*v as usize] as char);
+    }
+    Ok(out)
+}
+
+/// Decodes a bech32 string into its lowercase hrp and 5-bit data, without the checksum.
+/// Mixed-case strings are rejected, as are bech32m checksums.
+pub fn decode(s: &str) -> Result<(String, Vec<u8>), EncodingError> {
+    match decode_variant(s)? {
+        (hrp, data, Variant::Bech32) => Ok((hrp, data)),
+        (_, _, Variant::Bech32m) => Err(EncodingError::ChecksumMismatch(format!(
+            "{} has a bech32m checksum",
+            s
+        ))),
+    }
+}
+
+/// Like [`decode`], accepting either checksum and reporting which one matched.
+pub fn decode_variant(s: &str) -> Result<(String, Vec<u8>, Variant), EncodingError> {
+    if s.len() > MAX_LENGTH {
+        return Err(EncodingError::InvalidLength(format!(
+            "{} characters exceeds the limit of {}",
+            s.len(),
+            MAX_LENGTH
+        )));
+    }
+    if s.bytes().any(|c| c.is_ascii_lowercase()) && s.bytes().any(|c| c.is_ascii_uppercase()) {
+        return Err(EncodingError::InvalidCharacter(
+            "mixed-case bech32 string".to_string(),
+        ));
+    }
+
+    let s = s.to_ascii_lowercase();
+    let pos = s
+        .rfind('1')
+        .ok_or_else(|| EncodingError::InvalidPayload("missing separator '1'".to_string()))?;
+    let (hrp, rest) = (&s[..pos], &s[pos + 1..]);
+    check_hrp(hrp)?;
+    if rest.len() < CHECKSUM_LENGTH {
+        return Err(EncodingError::InvalidLength(format!(
+            "{} data characters is too short for a checksum",
+            rest.len()
+        )));
+    }
+
+    let data = rest
+        .chars()
+        .map(|c| {
+            CHARSET
+                .iter()
+                .position(|a| *a as char == c)
+                .map(|v| v as u8)
+                .ok_or_else(|| EncodingError::InvalidCharacter(format!("{:?} is not bech32", c)))
+        })
+        .collect::<Result<Vec<u8>, _>>()?;
+
+    let variant = Variant::from_constant(polymod(hrp_expand(hrp).chain(data.iter().copied())))
+        .ok_or_else(|| EncodingError::ChecksumMismatch(format!("bad checksum for {}", s)))?;
+    Ok((
+        hrp.to_string(),
+        data[..data.len() - CHECKSUM_LENGTH].to_vec(),
+        variant,
+    ))
+}
+
+/// Regroups `data` from `from`-bit to `to`-bit values. Without `pad`, leftover bits must
+/// be fewer than `from` and all zero.
+pub fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Result<Vec<u8>, EncodingError> {
+    let max = (1u32 << to) - 1;
+    let mut acc = 0u32;
+    let mut bits = 0u32;
+    let mut out = Vec::with_capacity(data.len() * from as usize / to as usize + 1);
+
+    for v in data {
+        if u32::from(*v) >> from != 0 {
+            return Err(EncodingError::InvalidPayload(format!(
+                "{} does not fit in {} bits",
+                v, from
+            )));
+        }
+        acc = (acc << from) | u32::from(*v);
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            out.push(((acc >> bits) & max) as u8);
+        }
+    }
+
+    if pad {
+        if bits > 0 {
+            out.push(((acc << (to - bits)) & max) as u8);
+        }
+    } else if bits >= from || (acc << (to - bits)) & max != 0 {
+        return Err(EncodingError::InvalidPayload(
+            "invalid padding in bit conversion".to_string(),
+        ));
+    }
+    Ok(out)
+}
+
+/// Encodes a witness program as a SegWit address, with bech32 for version 0 and bech32m
+/// for versions 1 to 16.
+pub fn encode_segwit(hrp: &str, version: u8, program: &[u8]) -> Result<String, EncodingError> {
+    check_witness(version, program)?;
+    let mut data = vec![version];
+    data.extend(convert_bits(program, 8, 5, true)?);
+    encode_variant(hrp, &data, witness_variant(version))
+}
+
+/// Decodes a SegWit address for the expected `hrp`, returning the witness version and
+/// program.
+pub fn decode_segwit(hrp: &str, address: &str) -> Result<(u8, Vec<u8>), EncodingError> {
+    let (got, data, variant) = decode_variant(address)?;
+    if got != hrp {
+        return Err(EncodingError::InvalidPayload(format!(
+            "expected hrp {}, got {}",
+            hrp, got
+        )));
+    }
+    let (version, program) = data
+        .split_first()
+        .ok_or_else(|| EncodingError::InvalidLength("missing witness version".to_string()))?;
+    if *version <= 16 && variant != witness_variant(*version) {
+        return Err(EncodingError::ChecksumMismatch(format!(
+            "witness version {} requires {:?}",
+            version,
+            witness_variant(*version)
+        )));
+    }
+    let program = convert_bits(program, 5, 8, false)?;
+    check_witness(*version, &program)?;
+    Ok((*version, program))
+}
+
+fn witness_variant(version: u8) -> Variant {
+    if version == 0 {
+        Variant::Bech32
+    } else {
+        Variant::Bech32m
+    }
+}
+
+fn check_witness(version: u8, program: &[u8]) -> Result<(), EncodingError> {
+    if version > 16 {
+        return Err(EncodingError::InvalidVersion(format!(
+            "witness version {} is not in 0..=16",
+            version
+        )));
+    }
+    if !(2..=40).contains(&program.len()) {
+        return Err(EncodingError::InvalidLength(format!(
+            "witness program is {} bytes, expected 2 to 40",
+            program.len()
+        )));
+    }
+    if version == 0 && program.len() != 20 && program.len() != 32 {
+        return Err(EncodingError::InvalidLength(format!(
+            "v0 witness program is {} bytes, expected 20 or 32",
+            program.len()
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::hex;
+
+    fn hex(input: &str) -> Vec<u8> {
+        hex::decode(input).unwrap()
+    }
+
+    #[test]
+    fn bip173_valid_checksums() {
+        let valid = [
+            "A12UEL5L",
+            "a12uel5l",
+            "an83characterlonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1tt5tgs",
+            "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw",
+            "11qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqc8247j",
+            "split1checkupstagehandshakeupstreamerranterredcaperred2y9e3w",
+            "?1ezyfcl",
+        ];
+        for s in valid {
+            let (hrp, data) = decode(s).unwrap();
+            assert_eq!(encode(&hrp, &data).unwrap(), s.to_ascii_lowercase());
+        }
+    }
+
+    #[test]
+    fn bip173_invalid_checksums() {
+        let invalid = [
+            // hrp character out of range
+            "\x201nwldj5",
+            "\x7f1axkwrx",
+            "\u{80}1eym55h",
+            // overall max length exceeded
+            "an84characterslonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1569pvx",
+            // no separator
+            "pzry9x0s0muk",
+            // empty hrp
+            "1pzry9x0s0muk",
+            "10a06t8",
+            "1qzzfhee",
+            // invalid data character
+            "x1b4n0q5v",
+            // too short checksum
+            "li1dgmt3",
+            // invalid character in checksum
+            "de1lg7wt\u{ff}",
+            // checksum calculated with the uppercase form of the hrp
+            "A1G7SGD8",
+        ];
+        for s in invalid {
+            assert!(decode(s).is_err(), "{:?} should not decode", s);
+        }
+
+        assert!(matches!(
+            decode("a12uel5m"),
+            Err(EncodingError::ChecksumMismatch(_))
+        ));
+        assert!(matches!(
+            decode("A12uEL5L"),
+            Err(EncodingError::InvalidCharacter(_))
+        ));
+    }
+
+    #[test]
+    fn bip173_valid_addresses() {
+        let valid = [
+            (
+                "bc",
+                "BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4",
+                "751e76e8199196d454941c45d1b3a323f1433bd6",
+            ),
+            (
+                "tb",
+                "tb1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3q0sl5k7",
+                "1863143c14c5166804bd19203356da136c985678cd4d27a1b8c6329604903262",
+            ),
+            (
+                "tb",
+                "tb1qqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesrxh6hy",
+                "000000c4a5cad46221b2a187905e5266362b99d5e91c6ce24d165dab93e86433",
+            ),
+        ];
+        for (hrp, address, program) in valid {
+            let (version, decoded) = decode_segwit(hrp, address).unwrap();
+            assert_eq!((version, decoded.clone()), (0, hex(program)));
+            assert_eq!(
+                encode_segwit(hrp, version, &decoded).unwrap(),
+                address.to_ascii_lowercase()
+            );
+        }
+    }
+
+    #[test]
+    fn bip173_invalid_addresses() {
+        let invalid = [
+            // invalid hrp
+            ("bc", "tc1qw508d6qejxtdg4y5r3zarvary0c5xw7kg3g4ty"),
+            // invalid checksum
+            ("bc", "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5"),
+            // invalid witness version
+            ("bc", "BC13W508D6QEJXTDG4Y5R3ZARVARY0C5XW7KN40WF2"),
+            // invalid program length
+            ("bc", "bc1rw5uspcuh"),
+            (
+                "bc",
+                "bc10w508d6qejxtdg4y5r3zarvary0c5xw7kw508d6qejxtdg4y5r3zarvary0c5xw7kw5rljs90",
+            ),
+            // invalid program length for witness version 0
+            ("bc", "BC1QR508D6QEJXTDG4Y5R3ZARVARYV98GJ9P"),
+            // mixed case
+            (
+                "tb",
+                "tb1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3q0sL5k7",
+            ),
+            // zero padding of more than 4 bits
+            ("bc", "bc1zw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"),
+            // non-zero padding in 8-to-5 conversion
+            (
+                "tb",
+                "tb1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3pjxtptv",
+            ),
+            // empty data section
+            ("bc", "bc1gmk9yu"),
+        ];
+        for (hrp, address) in invalid {
+            assert!(
+                decode_segwit(hrp, address).is_err(),
+                "{} should not decode",
+                address
+            );
+        }
+    }
+
+    #[test]
+    fn bip350_valid_checksums() {
+        let valid = [
+            "A1LQFN3A",
+            "a1lqfn3a",
+            "an83characterlonghumanreadablepartthatcontainsthetheexcludedcharactersbioandnumber11sg7hg6",
+            "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx",
+            "11llllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllludsr8",
+            "split1checkupstagehandshakeupstreamerranterredcaperredlc445v",
+            "?1v759aa",
+        ];
+        for s in valid {
+            let (hrp, data, variant) = decode_variant(s).unwrap();
+            assert_eq!(variant, Variant::Bech32m);
+            assert_eq!(
+                encode_variant(&hrp, &data, variant).unwrap(),
+                s.to_ascii_lowercase()
+            );
+            assert!(matches!(decode(s), Err(EncodingError::ChecksumMismatch(_))));
+        }
+    }
+
+    #[test]
+    fn bip350_invalid_checksums() {
+        let invalid = [
+            // hrp character out of range
+            "\x201xj0phk",
+            "\x7f1g6xzxy",
+            "\u{80}1vctc34",
+            // overall max length exceeded
+            "an84characterslonghumanreadablepartthatcontainsthetheexcludedcharactersbioandnumber11d6pts4",
+            // no separator
+            "qyrz8wqd2c9m",
+            // empty hrp
+            "1qyrz8wqd2c9m",
+            "16plkw9",
+            "1p2gdwpf",
+            // invalid data character
+            "y1b0jsk6g",
+            "lt1igcx5c0",
+            // too short checksum
+            "in1muywd",
+            // invalid character in checksum
+            "mm1crxm3i",
+            "au1s5cgom",
+            // checksum calculated with the uppercase form of the hrp
+            "M1VUXWEZ",
+        ];
+        for s in invalid {
+            assert!(decode_variant(s).is_err(), "{:?} should not decode", s);
+        }
+    }
+
+    #[test]
+    fn bip350_valid_addresses() {
+        let valid = [
+            (
+                "bc",
+                "bc1pw508d6qejxtdg4y5r3zarvary0c5xw7kw508d6qejxtdg4y5r3zarvary0c5xw7kt5nd6y",
+                1,
+                "751e76e8199196d454941c45d1b3a323f1433bd6751e76e8199196d454941c45d1b3a323f1433bd6",
+            ),
+            ("bc", "BC1SW50QGDZ25J", 16, "751e"),
+            (
+                "bc",
+                "bc1zw508d6qejxtdg4y5r3zarvaryvaxxpcs",
+                2,
+                "751e76e8199196d454941c45d1b3a323",
+            ),
+            (
+                "tb",
+                "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                1,
+                "000000c4a5cad46221b2a187905e5266362b99d5e91c6ce24d165dab93e86433",
+            ),
+            (
+                "bc",
+                "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0",
+                1,
+                "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            ),
+        ];
+        for (hrp, address, version, program) in valid {
+            assert_eq!(
+                decode_segwit(hrp, address).unwrap(),
+                (version, hex(program))
+            );
+            assert_eq!(
+                encode_segwit(hrp, version, &hex(program)).unwrap(),
+                address.to_ascii_lowercase()
+            );
+        }
+    }
+
+    #[test]
+    fn bip350_invalid_addresses() {
+        let invalid = [
+            // invalid hrp
+            (
+                "bc",
+                "tc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vq5zuyut",
+            ),
+            // bech32 instead of bech32m for v1+
+            (
+                "bc",
+                "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqh2y7hd",
+            ),
+            (
+                "tb",
+                "tb1z0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqglt7rf",
+            ),
+            (
+                "bc",
+                "BC1S0XLXVLHEMJA6C4DQV22UAPCTQUPFHLXM9H8Z3K2E72Q4K9HCZ7VQ54WELL",
+            ),
+            // bech32m instead of bech32 for v0
+            ("bc", "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kemeawh"),
+            (
+                "tb",
+                "tb1q0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vq24jc47",
+            ),
+            // invalid character in checksum
+            (
+                "bc",
+                "bc1p38j9r5y49hruaue7wxjce0updqjuyyx0kh56v8s25huc6995vvpql3jow4",
+            ),
+            // invalid witness version
+            (
+                "bc",
+                "BC130XLXVLHEMJA6C4DQV22UAPCTQUPFHLXM9H8Z3K2E72Q4K9HCZ7VQ7ZWS8R",
+            ),
+            // invalid program length
+            ("bc", "bc1pw5dgrnzv"),
+            (
+                "bc",
+                "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7v8n0nx0muaewav253zgeav",
+            ),
+            ("bc", "BC1QR508D6QEJXTDG4Y5R3ZARVARYV98GJ9P"),
+            // mixed case
+            (
+                "tb",
+                "tb1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vq47Zagq",
+            ),
+            // zero padding of more than 4 bits
+            (
+                "bc",
+                "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7v07qwwzcrf",
+            ),
+            // non-zero padding in 8-to-5 conversion
+            (
+                "tb",
+                "tb1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vpggkg4j",
+            ),
+            // empty data section
+            ("bc", "bc1gmk9yu"),
+        ];
+        for (hrp, address) in invalid {
+            assert!(
+                decode_segwit(hrp, address).is_err(),
+                "{} should not decode",
+                address
+            );
+        }
+
+        assert!(matches!(
+            decode_segwit(
+                "bc",
+                "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqh2y7hd"
+            ),
+            Err(EncodingError::ChecksumMismatch(_))
+        ));
+        assert!(matches!(
+            decode_segwit(
+                "tb",
+                "tb1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vq47Zagq"
+            ),
+            Err(EncodingError::InvalidCharacter(_))
+        ));
+    }
+
+    #[test]
+    fn convert_bits_round_trip() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        let fives = convert_bits(&bytes, 8, 5, true).unwrap();
+        assert!(fives.iter().all(|v| *v < 32));
+        assert_eq!(convert_bits(&fives, 5, 8, false).unwrap(), bytes);
+
+        assert!(convert_bits(&[32], 5, 8, true).is_err());
+    }
+
+    #[test]
+    fn encode_rejects_invalid_input() {
+        assert!(encode("", &[]).is_err());
+        assert!(encode("bc", &[32]).is_err());
+        assert!(encode("bc", &[0; 82]).is_err());
+        assert!(encode_segwit("bc", 0, &[0; 21]).is_err());
+    }
+}
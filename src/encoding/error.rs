@@ -0,0 +1,37 @@
+use alloc::string::String;
+use core::fmt;
+use core::fmt::Formatter;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EncodingError {
+    InvalidCharacter(String),
+    InvalidLength(String),
+    ChecksumMismatch(String),
+    InvalidVersion(String),
+    InvalidPayload(String),
+}
+
+impl fmt::Display for EncodingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodingError::InvalidCharacter(err) => {
+                write!(f, "InvalidCharacter({})", err)
+            }
+            EncodingError::InvalidLength(err) => {
+                write!(f, "InvalidLength({})", err)
+            }
+            EncodingError::ChecksumMismatch(err) => {
+                write!(f, "ChecksumMismatch({})", err)
+            }
+            EncodingError::InvalidVersion(err) => {
+                write!(f, "InvalidVersion({})", err)
+            }
+            EncodingError::InvalidPayload(err) => {
+                write!(f, "InvalidPayload({})", err)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EncodingError {}
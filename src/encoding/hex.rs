@@ -0,0 +1,88 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::encoding::error::EncodingError;
+
+const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Lowercase hex, two digits per byte.
+pub fn encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        out.push(DIGITS[(byte >> 4) as usize] as char);
+        out.push(DIGITS[(byte & 0x0f) as usize] as char);
+    }
+    out
+}
+
+/// Decodes hex in either case, with an optional `0x` prefix.
+pub fn decode(s: &str) -> Result<Vec<u8>, EncodingError> {
+    let s = s.strip_prefix("0x").unwrap_or(s).as_bytes();
+    if !s.len().is_multiple_of(2) {
+        return Err(EncodingError::InvalidLength(format!(
+            "hex length {} is odd",
+            s.len()
+        )));
+    }
+
+    s.chunks(2)
+        .map(|pair| Ok((digit(pair[0])? << 4) | digit(pair[1])?))
+        .collect()
+}
+
+fn digit(c: u8) -> Result<u8, EncodingError> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(EncodingError::InvalidCharacter(format!(
+            "{:?} is not a hex digit",
+            c as char
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        assert_eq!(decode(&encode(&bytes)).unwrap(), bytes);
+        assert_eq!(encode(&[0x00, 0x0f, 0xa5, 0xff]), "000fa5ff");
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(encode(&[]), "");
+        assert_eq!(decode("").unwrap(), Vec::<u8>::new());
+        assert_eq!(decode("0x").unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn uppercase_and_prefix() {
+        assert_eq!(decode("DEADbeef").unwrap(), vec![0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(decode("0xDEADBEEF").unwrap(), vec![0xde, 0xad, 0xbe, 0xef]);
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        assert!(matches!(
+            decode("abc"),
+            Err(EncodingError::InvalidLength(_))
+        ));
+        assert!(matches!(
+            decode("0xabc"),
+            Err(EncodingError::InvalidLength(_))
+        ));
+        for s in ["zz", "0g", " a", "0X0a", "+1"] {
+            assert!(
+                matches!(decode(s), Err(EncodingError::InvalidCharacter(_))),
+                "{:?} should not decode",
+                s
+            );
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Little-endian fixed-width integers, as used for versions, amounts and lock times in
+//! transaction serialization.
+
+use alloc::format;
+use alloc::vec::Vec;
+
+use crate::encoding::error::EncodingError;
+
+/// Reads a little-endian `u32` from the start of `bytes`, returning the value and the number
+/// of bytes consumed.
+pub fn read_u32(bytes: &[u8]) -> Result<(u32, usize), EncodingError> {
+    Ok((u32::from_le_bytes(read_array(bytes)?), 4))
+}
+
+/// Reads a little-endian `u64` from the start of `bytes`, returning the value and the number
+/// of bytes consumed.
+pub fn read_u64(bytes: &[u8]) -> Result<(u64, usize), EncodingError> {
+    Ok((u64::from_le_bytes(read_array(bytes)?), 8))
+}
+
+/// `n` as 4 little-endian bytes.
+pub fn write_u32(n: u32) -> Vec<u8> {
+    n.to_le_bytes().to_vec()
+}
+
+/// `n` as 8 little-endian bytes.
+pub fn write_u64(n: u64) -> Vec<u8> {
+    n.to_le_bytes().to_vec()
+}
+
+fn read_array<const N: usize>(bytes: &[u8]) -> Result<[u8; N], EncodingError> {
+    bytes
+        .get(..N)
+        .map(|body| body.try_into().unwrap())
+        .ok_or_else(|| {
+            EncodingError::InvalidLength(format!("need {} bytes, got {}", N, bytes.len()))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for n in [0, 0xdeadbeef, u32::MAX] {
+            assert_eq!(read_u32(&write_u32(n)).unwrap(), (n, 4));
+        }
+        for n in [0, 0xdeadbeef, u64::MAX] {
+            assert_eq!(read_u64(&write_u64(n)).unwrap(), (n, 8));
+        }
+        assert_eq!(write_u32(0xdeadbeef), [0xef, 0xbe, 0xad, 0xde]);
+    }
+
+    #[test]
+    fn read_ignores_trailing_bytes() {
+        assert_eq!(read_u32(&[1, 0, 0, 0, 0xff]).unwrap(), (1, 4));
+    }
+
+    #[test]
+    fn read_rejects_short_input() {
+        assert!(matches!(
+            read_u32(&[0xef, 0xbe, 0xad]),
+            Err(EncodingError::InvalidLength(_))
+        ));
+        assert!(matches!(
+            read_u64(&[0; 7]),
+            Err(EncodingError::InvalidLength(_))
+        ));
+    }
+}
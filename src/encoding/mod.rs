@@ -0,0 +1,8 @@
+pub mod address;
+pub mod base58;
+pub mod base64;
+pub mod bech32;
+pub mod error;
+pub mod hex;
+pub mod le;
+pub mod varint;
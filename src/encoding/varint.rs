@@ -0,0 +1,86 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+use crate::encoding::error::EncodingError;
+
+/// Bitcoin's CompactSize integer: one byte below `0xfd`, otherwise a `0xfd`, `0xfe` or
+/// `0xff` marker followed by a little-endian `u16`, `u32` or `u64`.
+pub fn encode_varint(n: u64) -> Vec<u8> {
+    match n {
+        0..=0xfc => vec![n as u8],
+        0xfd..=0xffff => [&[0xfd], &(n as u16).to_le_bytes()[..]].concat(),
+        0x1_0000..=0xffff_ffff => [&[0xfe], &(n as u32).to_le_bytes()[..]].concat(),
+        _ => [&[0xff], &n.to_le_bytes()[..]].concat(),
+    }
+}
+
+/// Reads a varint from the start of `bytes`, returning the value and the number of bytes
+/// consumed. Non-minimal encodings are rejected.
+pub fn decode_varint(bytes: &[u8]) -> Result<(u64, usize), EncodingError> {
+    let (len, min) = match bytes.first() {
+        None => return Err(EncodingError::InvalidLength("empty varint".to_string())),
+        Some(b) if *b < 0xfd => return Ok((u64::from(*b), 1)),
+        Some(0xfd) => (2, 0xfd),
+        Some(0xfe) => (4, 0x1_0000),
+        Some(_) => (8, 0x1_0000_0000),
+    };
+
+    let body = bytes
+        .get(1..1 + len)
+        .ok_or_else(|| EncodingError::InvalidLength(format!("varint needs {} more bytes", len)))?;
+    let mut le = [0u8; 8];
+    le[..len].copy_from_slice(body);
+    let n = u64::from_le_bytes(le);
+
+    if n < min {
+        return Err(EncodingError::InvalidPayload(format!(
+            "non-minimal varint for {}",
+            n
+        )));
+    }
+    Ok((n, 1 + len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let vectors: [(u64, &[u8]); 7] = [
+            (0, &[0x00]),
+            (0xfc, &[0xfc]),
+            (0xfd, &[0xfd, 0xfd, 0x00]),
+            (0xffff, &[0xfd, 0xff, 0xff]),
+            (0x1_0000, &[0xfe, 0x00, 0x00, 0x01, 0x00]),
+            (0x1_0000_0000, &[0xff, 0, 0, 0, 0, 1, 0, 0, 0]),
+            (u64::MAX, &[0xff; 9]),
+        ];
+        for (n, bytes) in vectors {
+            assert_eq!(encode_varint(n), bytes);
+            assert_eq!(decode_varint(bytes).unwrap(), (n, bytes.len()));
+        }
+    }
+
+    #[test]
+    fn decode_ignores_trailing_bytes() {
+        assert_eq!(decode_varint(&[0x05, 0xaa, 0xbb]).unwrap(), (5, 1));
+    }
+
+    #[test]
+    fn decode_rejects_malformed() {
+        assert!(matches!(
+            decode_varint(&[]),
+            Err(EncodingError::InvalidLength(_))
+        ));
+        assert!(matches!(
+            decode_varint(&[0xfe, 0x00, 0x01]),
+            Err(EncodingError::InvalidLength(_))
+        ));
+        assert!(matches!(
+            decode_varint(&[0xfd, 0x10, 0x00]),
+            Err(EncodingError::InvalidPayload(_))
+        ));
+    }
+}
@@ -0,0 +1,116 @@
+use core::fmt;
+use core::fmt::Formatter;
+
+use crate::ecc::error::{Bip32Error, FieldElementError, MusigError, SignatureError};
+use crate::encoding::error::EncodingError;
+
+/// Crate-wide error wrapping the error type of each module.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    Field(FieldElementError),
+    Signature(SignatureError),
+    Encoding(EncodingError),
+    Bip32(Bip32Error),
+    Musig(MusigError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Field(err) => write!(f, "Field({})", err),
+            Error::Signature(err) => write!(f, "Signature({})", err),
+            Error::Encoding(err) => write!(f, "Encoding({})", err),
+            Error::Bip32(err) => write!(f, "Bip32({})", err),
+            Error::Musig(err) => write!(f, "Musig({})", err),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Field(err) => Some(err),
+            Error::Signature(err) => Some(err),
+            Error::Encoding(err) => Some(err),
+            Error::Bip32(err) => Some(err),
+            Error::Musig(err) => Some(err),
+        }
+    }
+}
+
+impl From<FieldElementError> for Error {
+    fn from(err: FieldElementError) -> Self {
+        Error::Field(err)
+    }
+}
+
+impl From<SignatureError> for Error {
+    fn from(err: SignatureError) -> Self {
+        Error::Signature(err)
+    }
+}
+
+impl From<EncodingError> for Error {
+    fn from(err: EncodingError) -> Self {
+        Error::Encoding(err)
+    }
+}
+
+impl From<Bip32Error> for Error {
+    fn from(err: Bip32Error) -> Self {
+        Error::Bip32(err)
+    }
+}
+
+impl From<MusigError> for Error {
+    fn from(err: MusigError) -> Self {
+        Error::Musig(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecc::private_key::PrivateKey;
+    use crate::ecc::signature::Signature;
+
+    fn wif_to_der(wif: &str) -> crate::Result<Vec<u8>> {
+        let (key, _, _) = PrivateKey::from_wif(wif)?;
+        let sig = key.sign(&num_bigint::BigInt::from(1u8))?;
+        let der = sig.to_der();
+        Signature::from_der(&der)?;
+        Ok(der)
+    }
+
+    #[test]
+    fn question_mark_converts_module_errors() {
+        assert!(matches!(
+            wif_to_der("not a wif"),
+            Err(Error::Encoding(EncodingError::InvalidCharacter(_)))
+        ));
+
+        let wif = PrivateKey::from_bytes(&[1u8; 32]).unwrap().wif(true, false);
+        assert!(wif_to_der(&wif).is_ok());
+    }
+
+    #[test]
+    fn display_wraps_inner_error() {
+        let err = Error::from(SignatureError::InvalidDer("empty".to_string()));
+        assert_eq!(err.to_string(), "Signature(InvalidDer(empty))");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source_is_inner_error() {
+        use std::error::Error as _;
+
+        let inner = EncodingError::InvalidLength("short".to_string());
+        let err = Error::from(inner.clone());
+        assert_eq!(err.source().unwrap().to_string(), inner.to_string());
+
+        // boxes like any other std error
+        let boxed: Box<dyn std::error::Error> = Box::new(err);
+        assert_eq!(boxed.to_string(), "Encoding(InvalidLength(short))");
+    }
+}
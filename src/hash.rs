@@ -0,0 +1,113 @@
+use alloc::boxed::Box;
+
+use once_cell::race::OnceBox;
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+
+/// Tags whose midstate is computed once and reused.
+const KNOWN_TAGS: [&str; 8] = [
+    "BIP0340/aux",
+    "BIP0340/nonce",
+    "BIP0340/challenge",
+    "TapLeaf",
+    "TapBranch",
+    "TapTweak",
+    "KeyAgg list",
+    "KeyAgg coefficient",
+];
+
+static MIDSTATES: [OnceBox<Sha256>; KNOWN_TAGS.len()] =
+    [const { OnceBox::new() }; KNOWN_TAGS.len()];
+
+/// Double SHA-256, Bitcoin's standard hash for transactions and checksums.
+pub fn hash256(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(Sha256::digest(data)).into()
+}
+
+/// `ripemd160(sha256(data))`, used for P2PKH/P2WPKH public key hashes.
+pub fn hash160(data: &[u8]) -> [u8; 20] {
+    Ripemd160::digest(Sha256::digest(data)).into()
+}
+
+/// BIP340 tagged hash `sha256(sha256(tag) || sha256(tag) || msg)`.
+///
+/// The 64-byte prefix is exactly one SHA-256 block, so the hasher state after it only
+/// depends on the tag. For the BIP340/BIP341 tags that state is cached and cloned.
+pub fn tagged_hash(tag: &str, msg: &[u8]) -> [u8; 32] {
+    let mut hasher = match KNOWN_TAGS.iter().position(|known| *known == tag) {
+        Some(i) => MIDSTATES[i]
+            .get_or_init(|| Box::new(tag_midstate(tag)))
+            .clone(),
+        None => tag_midstate(tag),
+    };
+    hasher.update(msg);
+    hasher.finalize().into()
+}
+
+fn tag_midstate(tag: &str) -> Sha256 {
+    let tag_hash = Sha256::digest(tag.as_bytes());
+    Sha256::new().chain_update(tag_hash).chain_update(tag_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::hex;
+
+    #[test]
+    fn hash256_empty() {
+        assert_eq!(
+            hash256(b""),
+            [
+                0x5d, 0xf6, 0xe0, 0xe2, 0x76, 0x13, 0x59, 0xd3, 0x0a, 0x82, 0x75, 0x05, 0x8e, 0x29,
+                0x9f, 0xcc, 0x03, 0x81, 0x53, 0x45, 0x45, 0xf5, 0x5c, 0xf4, 0x3e, 0x41, 0x98, 0x3f,
+                0x5d, 0x4c, 0x94, 0x56
+            ]
+        );
+    }
+
+    #[test]
+    fn hash160_empty() {
+        assert_eq!(
+            hash160(b""),
+            [
+                0xb4, 0x72, 0xa2, 0x66, 0xd0, 0xbd, 0x89, 0xc1, 0x37, 0x06, 0xa4, 0x13, 0x2c, 0xcf,
+                0xb1, 0x6f, 0x7c, 0x3b, 0x9f, 0xcb
+            ]
+        );
+    }
+
+    #[test]
+    fn tagged_hash_bip340_challenge() {
+        // R.x || P.x || m from the first BIP340 test vector
+        let msg = hex::decode(
+            "e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca8215\
+             f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9\
+             0000000000000000000000000000000000000000000000000000000000000000",
+        )
+        .unwrap();
+        let expected = "6bb6b93a91f2ecc0cd924f4f9baabb5e6eb21745bb00f2cebdaac908bb5d86ce";
+
+        assert_eq!(
+            hex::encode(&tagged_hash("BIP0340/challenge", &msg)),
+            expected
+        );
+        // the cached midstate gives the same answer the second time round
+        assert_eq!(
+            hex::encode(&tagged_hash("BIP0340/challenge", &msg)),
+            expected
+        );
+    }
+
+    #[test]
+    fn tagged_hash_uncached_tag() {
+        assert_eq!(
+            hex::encode(&tagged_hash("rbtc", b"abc")),
+            "f08ac707602f74bb444b4168e261f3d8f5a71a1d15994561f6d9f9e3d8680c07"
+        );
+        assert_eq!(
+            hex::encode(&tagged_hash("TapTweak", b"")),
+            "8aa4229474ab0100b2d6f0687f031d1fc9d8eef92a042ad97d279bff456b15e4"
+        );
+    }
+}
@@ -0,0 +1,39 @@
+//! Bitcoin primitives built from scratch, following *Programming Bitcoin*.
+//!
+//! Every module keeps its own error type; [`Error`] wraps them all so a single
+//! [`Result`] works across module boundaries with `?`.
+//!
+//! ```
+//! use rbtc::ecc::private_key::PrivateKey;
+//! use rbtc::Result;
+//!
+//! fn public_sec(secret: &[u8; 32]) -> Result<[u8; 33]> {
+//!     let key = PrivateKey::from_bytes(secret)?;
+//!     Ok(key.public_key()?.to_sec_compressed())
+//! }
+//!
+//! assert!(public_sec(&[0u8; 32]).is_err());
+//! assert_eq!(public_sec(&[1u8; 32]).unwrap()[0] & 0xfe, 0x02);
+//! ```
+//!
+//! The default `std` feature can be turned off to build as `no_std`; the crate then only
+//! needs `alloc`.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[macro_use]
+mod macros;
+
+pub mod ecc;
+pub mod encoding;
+pub mod error;
+pub mod hash;
+pub mod prelude;
+
+pub use ecc::error::{FieldElementError, SignatureError};
+pub use encoding::error::EncodingError;
+pub use error::Error;
+
+pub type Result<T> = core::result::Result<T, Error>;
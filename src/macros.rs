@@ -0,0 +1,91 @@
+/// `FieldElement::new(num, prime).unwrap()`, panicking if `num` is outside `[0, prime)`.
+///
+/// ```
+/// use rbtc::fe;
+///
+/// let a = fe!(17, 31);
+/// assert_eq!((a.clone() + a).unwrap(), fe!(3, 31));
+/// ```
+#[macro_export]
+macro_rules! fe {
+    ($num:expr, $prime:expr) => {
+        $crate::ecc::field_element::FieldElement::new($num, $prime).unwrap()
+    };
+}
+
+/// A point on `y^2 = x^3 + ax + b` over `F_prime`, panicking if it is off the curve.
+/// `point!(a, b; prime)` is the point at infinity.
+///
+/// ```
+/// use rbtc::point;
+///
+/// let p = point!(0, 7, 47, 71; 223);
+/// assert_eq!((&p + &point!(0, 7; 223)).unwrap(), p);
+/// ```
+#[macro_export]
+macro_rules! point {
+    ($a:expr, $b:expr, $x:expr, $y:expr; $prime:expr) => {
+        $crate::ecc::point::point::Point::new(
+            $crate::fe!($a, $prime),
+            $crate::fe!($b, $prime),
+            Some($crate::fe!($x, $prime)),
+            Some($crate::fe!($y, $prime)),
+        )
+        .unwrap()
+    };
+    ($a:expr, $b:expr; $prime:expr) => {
+        $crate::ecc::point::point::Point::new(
+            $crate::fe!($a, $prime),
+            $crate::fe!($b, $prime),
+            None,
+            None,
+        )
+        .unwrap()
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ecc::field_element::FieldElement;
+    use crate::ecc::point::point::Point;
+
+    #[test]
+    fn fe_matches_constructor() {
+        assert_eq!(fe!(17, 31), FieldElement::new(17, 31).unwrap());
+        let prime = 223;
+        assert_eq!(fe!(2 * 96, prime), FieldElement::new(192, 223).unwrap());
+    }
+
+    #[test]
+    #[should_panic]
+    fn fe_panics_out_of_range() {
+        fe!(31, 31);
+    }
+
+    #[test]
+    fn point_matches_constructor() {
+        let explicit = Point::new(
+            FieldElement::new(0, 223).unwrap(),
+            FieldElement::new(7, 223).unwrap(),
+            Some(FieldElement::new(192, 223).unwrap()),
+            Some(FieldElement::new(105, 223).unwrap()),
+        )
+        .unwrap();
+        assert_eq!(point!(0, 7, 192, 105; 223), explicit);
+
+        let infinity = Point::new(
+            FieldElement::new(0, 223).unwrap(),
+            FieldElement::new(7, 223).unwrap(),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(point!(0, 7; 223), infinity);
+    }
+
+    #[test]
+    #[should_panic]
+    fn point_panics_off_curve() {
+        point!(0, 7, 200, 119; 223);
+    }
+}
@@ -1,7 +1,3 @@
-extern crate core;
-
-mod ecc;
-
 fn main() {
     println!("Hello, world!");
 }
@@ -0,0 +1,29 @@
+//! Commonly used types and traits in one import.
+//!
+//! ```
+//! use rbtc::prelude::*;
+//!
+//! fn double<F: FieldElementTrait + Clone>(p: &Point<F>) -> ArithmeticResult<Point<F>> {
+//!     p.clone() + p.clone()
+//! }
+//!
+//! let a = FieldElement::new(0, 223)?;
+//! let b = FieldElement::new(7, 223)?;
+//! let x = FieldElement::new(47, 223)?;
+//! let y = FieldElement::new(71, 223)?;
+//! let p = Point::new(a, b, Some(x), Some(y))?;
+//! assert_eq!(double(&p)?, (Scalar::from(2u8) * &p)?);
+//!
+//! let g = S256Point::generator();
+//! assert_eq!((Scalar::from(1u8) * &g)?, g);
+//! assert_eq!(S256Field::get_b()?, S256Field::new(7.into())?);
+//! # Ok::<(), FieldElementError>(())
+//! ```
+
+pub use crate::ecc::abstractions::{ArithmeticResult, FieldElementTrait};
+pub use crate::ecc::error::FieldElementError;
+pub use crate::ecc::field_element::FieldElement;
+pub use crate::ecc::point::point::Point;
+pub use crate::ecc::point::s256_point::S256Point;
+pub use crate::ecc::s256_field::S256Field;
+pub use crate::ecc::scalar::Scalar;
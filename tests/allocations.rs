@@ -0,0 +1,95 @@
+//! Counts heap allocations on the current thread to keep the scalar-multiply hot path lean.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::ops::Add;
+
+use num_bigint::BigInt;
+use num_traits::{Num, One, Zero};
+use rbtc::ecc::abstractions::{ArithmeticResult, FieldElementTrait};
+use rbtc::ecc::s256_field::S256Field;
+use rbtc::ecc::scalar::Scalar;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|a| a.set(a.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|a| a.set(a.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(|a| a.get());
+    let out = f();
+    (out, ALLOCATIONS.with(|a| a.get()) - before)
+}
+
+/// The previous `&Scalar * &F`: clones the coefficient and `current` on every bit.
+fn cloning_mul<F>(scalar: &Scalar, rhs: &F) -> ArithmeticResult<F>
+where
+    F: FieldElementTrait + Clone,
+    for<'x> &'x F: Add<&'x F, Output = ArithmeticResult<F>>,
+{
+    let mut coef = scalar.n.clone();
+    let one = BigInt::one();
+    let mut current = rhs.clone();
+    let mut result = rhs.zero()?;
+
+    while coef > BigInt::zero() {
+        if coef.clone() & &one == one {
+            result = (result + &current)?;
+        }
+        current = (current.clone() + current)?;
+        coef >>= 1;
+    }
+    Ok(result)
+}
+
+#[test]
+fn scalar_multiply_allocates_less_than_cloning_loop() {
+    let x = S256Field::new(BigInt::from(7u8)).unwrap();
+    let scalar = Scalar::new(
+        BigInt::from_str_radix(
+            "8f68b9d2f63b5f339239c1ad981f162ee88c5678723ea3351b7b444c9ec4c0da",
+            16,
+        )
+        .unwrap(),
+    );
+    // warm up the cached prime so its one-off allocation isn't counted
+    S256Field::prime();
+
+    let (expected, before) = count_allocations(|| cloning_mul(&scalar, &x).unwrap());
+    let (actual, after) = count_allocations(|| (&scalar * &x).unwrap());
+
+    assert_eq!(actual, expected);
+    assert!(after < before, "{} >= {}", after, before);
+}
+
+#[test]
+fn pow_mod_ref_does_not_allocate_the_exponent() {
+    let x = S256Field::new(BigInt::from(7u8)).unwrap();
+    let exponent = S256Field::prime() - BigInt::from(2u8);
+
+    let (expected, by_value) = count_allocations(|| x.pow_mod(exponent.clone()));
+    let (actual, by_ref) = count_allocations(|| x.pow_mod_ref(&exponent).unwrap());
+
+    assert_eq!(actual, expected);
+    assert!(by_ref < by_value, "{} >= {}", by_ref, by_value);
+}
@@ -0,0 +1,155 @@
+//! Feeds random, truncated and corrupted bytes to the SEC and DER parsers. They face untrusted
+//! input, so every slice has to come back as `Ok` or `Err`, never as a panic.
+
+use num_bigint::BigInt;
+use rbtc::ecc::point::s256_point::S256Point;
+use rbtc::ecc::private_key::PrivateKey;
+use rbtc::ecc::scalar::Scalar;
+use rbtc::ecc::signature::{hash_to_scalar, Signature};
+
+/// xorshift64*, seeded so a failure reproduces.
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    fn bytes(&mut self, len: usize) -> Vec<u8> {
+        (0..len).map(|_| self.next_u64() as u8).collect()
+    }
+}
+
+fn valid_secs() -> Vec<Vec<u8>> {
+    let mut secs = Vec::new();
+    for k in [1u32, 2, 6, 5000, 0xdead_beef] {
+        let point = (Scalar::from(k) * &S256Point::generator()).unwrap();
+        secs.push(point.to_sec_compressed().to_vec());
+        secs.push(point.to_sec_uncompressed().to_vec());
+    }
+    secs
+}
+
+fn valid_ders() -> Vec<Vec<u8>> {
+    let mut ders = Vec::new();
+    for (k, msg) in [(1u32, "a"), (12345, "b"), (0xdead_beef, "c")] {
+        let key = PrivateKey::new(Scalar::from(k)).unwrap();
+        ders.push(key.sign(&hash_to_scalar(msg.as_bytes())).unwrap().to_der());
+    }
+    ders
+}
+
+/// Parses `sec`, checking that anything accepted re-encodes to the same bytes.
+fn check_sec(sec: &[u8]) {
+    if let Ok(point) = S256Point::parse(sec) {
+        assert_eq!(point.serialize(sec.len() == 33), sec);
+    }
+}
+
+/// Runs both DER parsers on `der`. Anything the lax one accepts has `r` and `s` in `[1, N)`
+/// and so re-encodes without panicking; the strict one only accepts canonical encodings, so
+/// anything it accepts re-encodes to the same bytes.
+fn check_der(der: &[u8]) {
+    if let Ok(sig) = Signature::from_der(der) {
+        for scalar in [&sig.r, &sig.s] {
+            assert!(*scalar >= BigInt::from(1u8) && scalar < S256Point::order());
+        }
+        sig.to_compact();
+        sig.to_der();
+    }
+    if let Ok(sig) = Signature::from_der_strict(der) {
+        assert_eq!(sig.to_der(), der);
+    }
+}
+
+#[test]
+fn random_bytes() {
+    let mut rng = Rng(0x5eed_0001);
+    for len in 0..=100 {
+        for _ in 0..40 {
+            let bytes = rng.bytes(len);
+            check_sec(&bytes);
+            check_der(&bytes);
+        }
+    }
+}
+
+#[test]
+fn valid_prefix_with_garbage_body() {
+    let mut rng = Rng(0x5eed_0002);
+    for _ in 0..500 {
+        for (prefix, len) in [(0x02u8, 33usize), (0x03, 33), (0x04, 65)] {
+            let mut sec = rng.bytes(len);
+            sec[0] = prefix;
+            check_sec(&sec);
+        }
+
+        // sequence and integer tags in place, lengths and contents random
+        let len = 8 + (rng.next_u64() % 65) as usize;
+        let mut der = rng.bytes(len);
+        der[0] = 0x30;
+        der[1] = (len - 2) as u8;
+        der[2] = 0x02;
+        check_der(&der);
+
+        // also with the two integer lengths consistent
+        let rlen = der[3] as usize % (len - 6) + 1;
+        der[3] = rlen as u8;
+        if 4 + rlen + 2 <= len {
+            der[4 + rlen] = 0x02;
+            der[5 + rlen] = (len - 6 - rlen) as u8;
+        }
+        check_der(&der);
+    }
+}
+
+#[test]
+fn truncated_and_extended_encodings() {
+    let mut rng = Rng(0x5eed_0003);
+    for sec in valid_secs() {
+        for len in 0..sec.len() {
+            assert!(S256Point::parse(&sec[..len]).is_err());
+        }
+        let mut extended = sec.clone();
+        extended.push(rng.next_u64() as u8);
+        assert!(S256Point::parse(&extended).is_err());
+    }
+    for der in valid_ders() {
+        for len in 0..der.len() {
+            assert!(Signature::from_der(&der[..len]).is_err());
+            assert!(Signature::from_der_strict(&der[..len]).is_err());
+        }
+        let mut extended = der.clone();
+        extended.push(rng.next_u64() as u8);
+        assert!(Signature::from_der(&extended).is_err());
+        assert!(Signature::from_der_strict(&extended).is_err());
+    }
+}
+
+#[test]
+fn corrupted_encodings() {
+    let mut rng = Rng(0x5eed_0004);
+    let valid: Vec<_> = valid_secs().into_iter().chain(valid_ders()).collect();
+    for bytes in &valid {
+        // every single-bit flip
+        for i in 0..bytes.len() * 8 {
+            let mut flipped = bytes.clone();
+            flipped[i / 8] ^= 1 << (i % 8);
+            check_sec(&flipped);
+            check_der(&flipped);
+        }
+        // a few random bytes overwritten at once
+        for _ in 0..200 {
+            let mut corrupted = bytes.clone();
+            for _ in 0..1 + rng.next_u64() % 4 {
+                let i = (rng.next_u64() as usize) % corrupted.len();
+                corrupted[i] = rng.next_u64() as u8;
+            }
+            check_sec(&corrupted);
+            check_der(&corrupted);
+        }
+    }
+}
@@ -0,0 +1,362 @@
+//! Worked examples and exercises from *Programming Bitcoin*, chapters 1 to 4, run against the
+//! public API. Each table row is one vector from the book.
+
+use num_bigint::{BigInt, Sign};
+use num_traits::Num;
+use rbtc::ecc::private_key::PrivateKey;
+use rbtc::ecc::signature::Signature;
+use rbtc::encoding::{base58, hex};
+use rbtc::hash::hash256;
+use rbtc::prelude::*;
+
+fn from_hex(input: &str) -> BigInt {
+    BigInt::from_str_radix(input, 16).unwrap()
+}
+
+fn fe(num: i64, prime: i64) -> FieldElement {
+    FieldElement::new(num, prime).unwrap()
+}
+
+fn key(secret: BigInt) -> PrivateKey {
+    PrivateKey::new(Scalar::new(secret)).unwrap()
+}
+
+/// Point on the chapter 3 curve `y^2 = x^3 + 7` over F_223, `None` for infinity.
+fn f223_point(coords: Option<(i64, i64)>) -> Point<FieldElement> {
+    let (x, y) = match coords {
+        Some((x, y)) => (Some(fe(x, 223)), Some(fe(y, 223))),
+        None => (None, None),
+    };
+    Point::new(fe(0, 223), fe(7, 223), x, y).unwrap()
+}
+
+#[test]
+fn chapter_1_field_addition_and_subtraction() {
+    // F_57
+    let p = 57;
+    assert_eq!((fe(44, p) + fe(33, p)).unwrap(), fe(20, p));
+    assert_eq!((fe(9, p) - fe(29, p)).unwrap(), fe(37, p));
+    assert_eq!(
+        ((fe(17, p) + fe(42, p)).unwrap() + fe(49, p)).unwrap(),
+        fe(51, p)
+    );
+    assert_eq!(
+        ((fe(52, p) - fe(30, p)).unwrap() - fe(38, p)).unwrap(),
+        fe(41, p)
+    );
+}
+
+#[test]
+fn chapter_1_field_multiplication_and_exponentiation() {
+    let p = 97;
+    let product = |nums: &[i64]| {
+        nums.iter()
+            .map(|n| fe(*n, p))
+            .reduce(|acc, n| (acc * n).unwrap())
+            .unwrap()
+    };
+    assert_eq!(product(&[95, 45, 31]), fe(23, p));
+    assert_eq!(product(&[17, 13, 19, 44]), fe(68, p));
+    assert_eq!(
+        (fe(12, p).pow_mod(BigInt::from(7)) * fe(77, p).pow_mod(BigInt::from(49))).unwrap(),
+        fe(63, p)
+    );
+}
+
+#[test]
+fn chapter_1_field_division() {
+    let p = 31;
+    assert_eq!((fe(3, p) / fe(24, p)).unwrap(), fe(4, p));
+    assert_eq!(fe(17, p).pow_mod(BigInt::from(-3)), fe(29, p));
+    assert_eq!(
+        (fe(4, p).pow_mod(BigInt::from(-4)) * fe(11, p)).unwrap(),
+        fe(13, p)
+    );
+}
+
+#[test]
+fn chapter_3_points_on_f223_curve() {
+    let on_curve = [(192, 105), (17, 56), (1, 193)];
+    let off_curve = [(200, 119), (42, 99)];
+
+    for (x, y) in on_curve {
+        assert!(Point::new(fe(0, 223), fe(7, 223), Some(fe(x, 223)), Some(fe(y, 223))).is_ok());
+    }
+    for (x, y) in off_curve {
+        assert!(matches!(
+            Point::new(fe(0, 223), fe(7, 223), Some(fe(x, 223)), Some(fe(y, 223))),
+            Err(FieldElementError::NotOnCurve(_))
+        ));
+    }
+}
+
+#[test]
+fn chapter_3_point_addition() {
+    // (p1, p2, p1 + p2)
+    let vectors = [
+        ((170, 142), (60, 139), (220, 181)),
+        ((47, 71), (17, 56), (215, 68)),
+        ((143, 98), (76, 66), (47, 71)),
+        ((192, 105), (192, 105), (49, 71)),
+        ((143, 98), (143, 98), (64, 168)),
+    ];
+    for (p1, p2, sum) in vectors {
+        let (p1, p2) = (f223_point(Some(p1)), f223_point(Some(p2)));
+        assert_eq!((&p1 + &p2).unwrap(), f223_point(Some(sum)));
+    }
+}
+
+#[test]
+fn chapter_3_scalar_multiplication() {
+    let g = f223_point(Some((47, 71)));
+    // (k, k * (47, 71))
+    let vectors = [
+        (1u8, Some((47, 71))),
+        (2, Some((36, 111))),
+        (4, Some((194, 51))),
+        (8, Some((116, 55))),
+        (21, None),
+    ];
+    for (k, expected) in vectors {
+        assert_eq!((Scalar::from(k) * &g).unwrap(), f223_point(expected));
+    }
+    assert_eq!(g.order(100), Some(21));
+}
+
+#[test]
+fn chapter_3_group_order() {
+    let g = S256Point::generator();
+    let n = Scalar::new(S256Point::order().clone());
+    assert_eq!((n * &g).unwrap(), S256Point::new(None, None).unwrap());
+}
+
+#[test]
+fn chapter_3_verify_signatures() {
+    let point = S256Point::parse(
+        &hex::decode(concat!(
+            "04",
+            "887387e452b8eacc4acfde10d9aaf7f6d9a0f975aabb10d006e4da568744d06c",
+            "61de6d95231cd89026e286df3b6ae4a894a3378e393e93a0f45b666329a0ae34"
+        ))
+        .unwrap(),
+    )
+    .unwrap();
+
+    // (z, r, s)
+    let vectors = [
+        (
+            "ec208baa0fc1c19f708a9ca96fdeff3ac3f230bb4a7ba4aede4942ad003c0f60",
+            "ac8d1c87e51d0d441be8b3dd5b05c8795b48875dffe00b7ffcfac23010d3a395",
+            "068342ceff8935ededd102dd876ffd6ba72d6a427a3edb13d26eb0781cb423c4",
+        ),
+        (
+            "7c076ff316692a3d7eb3c3bb0f8b1488cf72e1afcd929e29307032997a838a3d",
+            "00eff69ef2b1bd93a66ed5219add4fb51e11a840f404876325a1e8ffe0529a2c",
+            "c7207fee197d27c618aea621406f6bf5ef6fca38681d82b2f06fddbdce6feab6",
+        ),
+    ];
+    for (z, r, s) in vectors {
+        let z = from_hex(z);
+        let sig = Signature::new(from_hex(r), from_hex(s)).unwrap();
+        assert!(point.verify(&z, &sig).unwrap());
+        assert!(!point.verify(&(z + 1), &sig).unwrap());
+    }
+}
+
+#[test]
+fn chapter_3_sign_with_chosen_k() {
+    // e = 12345, z = hash256("Programming Bitcoin!"), k = 1234567890
+    let e = Scalar::from(12345u32);
+    let z = BigInt::from_bytes_be(Sign::Plus, &hash256(b"Programming Bitcoin!"));
+    let k = Scalar::from(1234567890u32);
+
+    let r = (k.clone() * &S256Point::generator()).unwrap();
+    let r = Scalar::new(r.x().unwrap().get_num().clone());
+    let s = Scalar::new(z.clone())
+        .add_mod_n(&r.mul_mod_n(&e))
+        .mul_mod_n(&k.inverse_mod_n().unwrap());
+    assert_eq!(
+        r.n,
+        from_hex("2b698a0f0a4041b77e63488ad48c23e8e8838dd1fb7520408b121697b782ef22")
+    );
+    assert_eq!(
+        s.n,
+        from_hex("1dbc63bfef4416705e602a7b564161167076d8b20990a0f26f316cff2cb0bc1a")
+    );
+
+    let public_key = key(e.n).public_key().unwrap().clone();
+    assert_eq!(
+        hex::encode(&public_key.to_sec_uncompressed()),
+        concat!(
+            "04",
+            "f01d6b9018ab421dd410404cb869072065522bf85734008f105cf385a023a80f",
+            "0eba29d0f0c5408ed681984dc525982abefccd9f7ff01dd26da4999cf3f6a295"
+        )
+    );
+    assert!(public_key
+        .verify(&z, &Signature::new(r.n, s.n).unwrap())
+        .unwrap());
+}
+
+#[test]
+fn chapter_3_sign_and_verify() {
+    let private_key = key(BigInt::from(12345));
+    let z = BigInt::from_bytes_be(Sign::Plus, &hash256(b"Programming Bitcoin!"));
+
+    let sig = private_key.sign(&z).unwrap();
+    assert!(sig.is_low_s());
+    assert!(private_key.public_key().unwrap().verify(&z, &sig).unwrap());
+}
+
+#[test]
+fn chapter_4_sec_uncompressed() {
+    // (secret, SEC)
+    let vectors = [
+        (
+            BigInt::from(5000),
+            "04ffe558e388852f0120e46af2d1b370f85854a8eb0841811ece0e3e03d282d57c315dc72890a4f10a1481c031b03b351b0dc79901ca18a00cf009dbdb157a1d10",
+        ),
+        (
+            BigInt::from(2018).pow(5),
+            "04027f3da1918455e03c46f659266a1bb5204e959db7364d2f473bdf8f0a13cc9dff87647fd023c13b4a4994f17691895806e1b40b57f4fd22581a4f46851f3b06",
+        ),
+        (
+            from_hex("deadbeef12345"),
+            "04d90cd625ee87dd38656dd95cf79f65f60f7273b67d3096e68bd81e4f5342691f842efa762fd59961d0e99803c61edba8b3e3f7dc3a341836f97733aebf987121",
+        ),
+    ];
+    for (secret, sec) in vectors {
+        let point = key(secret).public_key().unwrap().clone();
+        assert_eq!(hex::encode(&point.to_sec_uncompressed()), sec);
+        assert_eq!(S256Point::parse(&hex::decode(sec).unwrap()).unwrap(), point);
+    }
+}
+
+#[test]
+fn chapter_4_sec_compressed() {
+    // (secret, SEC)
+    let vectors = [
+        (
+            BigInt::from(5001),
+            "0357a4f368868a8a6d572991e484e664810ff14c05c0fa023275251151fe0e53d1",
+        ),
+        (
+            BigInt::from(2019).pow(5),
+            "02933ec2d2b111b92737ec12f1c5d20f3233a0ad21cd8b36d0bca7a0cfa5cb8701",
+        ),
+        (
+            from_hex("deadbeef54321"),
+            "0296be5b1292f6c856b3c5654e886fc13511462059089cdf9c479623bfcbe77690",
+        ),
+    ];
+    for (secret, sec) in vectors {
+        let point = key(secret).public_key().unwrap().clone();
+        assert_eq!(hex::encode(&point.to_sec_compressed()), sec);
+        assert_eq!(S256Point::parse(&hex::decode(sec).unwrap()).unwrap(), point);
+    }
+}
+
+#[test]
+fn chapter_4_der() {
+    let sig = Signature::new(
+        from_hex("37206a0610995c58074999cb9767b87af4c4978db68c06e8e6e81d282047a7c6"),
+        from_hex("8ca63759c1157ebeaec0d03cecca119fc9a75bf8e6d0fa65c841c8e2738cdaec"),
+    )
+    .unwrap();
+    let der = concat!(
+        "3045",
+        "022037206a0610995c58074999cb9767b87af4c4978db68c06e8e6e81d282047a7c6",
+        "0221008ca63759c1157ebeaec0d03cecca119fc9a75bf8e6d0fa65c841c8e2738cdaec"
+    );
+    assert_eq!(hex::encode(&sig.to_der()), der);
+    assert_eq!(
+        Signature::from_der(&hex::decode(der).unwrap()).unwrap(),
+        sig
+    );
+}
+
+#[test]
+fn chapter_4_base58() {
+    // (bytes, base58)
+    let vectors = [
+        (
+            "7c076ff316692a3d7eb3c3bb0f8b1488cf72e1afcd929e29307032997a838a3d",
+            "9MA8fRQrT4u8Zj8ZRd6MAiiyaxb2Y1CMpvVkHQu5hVM6",
+        ),
+        (
+            "eff69ef2b1bd93a66ed5219add4fb51e11a840f404876325a1e8ffe0529a2c",
+            "4fE3H2E6XMp4SsxtwinF7w9a34ooUrwWe4WsW1458Pd",
+        ),
+        (
+            "c7207fee197d27c618aea621406f6bf5ef6fca38681d82b2f06fddbdce6feab6",
+            "EQJsjkd6JaGwxrjEhfeqPenqHwrBmPQZjJGNSCHBkcF7",
+        ),
+    ];
+    for (bytes, encoded) in vectors {
+        let bytes = hex::decode(bytes).unwrap();
+        assert_eq!(base58::encode(&bytes), encoded);
+        assert_eq!(base58::decode(encoded).unwrap(), bytes);
+    }
+}
+
+#[test]
+fn chapter_4_addresses() {
+    // (secret, compressed, testnet, address)
+    let vectors = [
+        (
+            BigInt::from(5002),
+            false,
+            true,
+            "mmTPbXQFxboEtNRkwfh6K51jvdtHLxGeMA",
+        ),
+        (
+            BigInt::from(2020).pow(5),
+            true,
+            true,
+            "mopVkxp8UhXqRYbCYJsbeE1h1fiF64jcoH",
+        ),
+        (
+            from_hex("12345deadbeef"),
+            true,
+            false,
+            "1F1Pn2y6pDb68E5nYJJeba4TLg2U7B6KF1",
+        ),
+    ];
+    for (secret, compressed, testnet, address) in vectors {
+        let point = key(secret).public_key().unwrap().clone();
+        assert_eq!(point.address(compressed, testnet), address);
+    }
+}
+
+#[test]
+fn chapter_4_wif() {
+    // (secret, compressed, testnet, WIF)
+    let vectors = [
+        (
+            BigInt::from(5003),
+            true,
+            true,
+            "cMahea7zqjxrtgAbB7LSGbcQUr1uX1ojuat9jZodMN8rFTv2sfUK",
+        ),
+        (
+            BigInt::from(2021).pow(5),
+            false,
+            true,
+            "91avARGdfge8E4tZfYLoxeJ5sGBdNJQH4kvjpWAxgzczjbCwxic",
+        ),
+        (
+            from_hex("54321deadbeef"),
+            true,
+            false,
+            "KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgiuQJv1h8Ytr2S53a",
+        ),
+    ];
+    for (secret, compressed, testnet, wif) in vectors {
+        let private_key = key(secret);
+        assert_eq!(private_key.wif(compressed, testnet), wif);
+
+        let (decoded, decoded_compressed, decoded_testnet) = PrivateKey::from_wif(wif).unwrap();
+        assert_eq!(decoded.secret(), private_key.secret());
+        assert_eq!((decoded_compressed, decoded_testnet), (compressed, testnet));
+    }
+}